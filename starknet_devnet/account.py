@@ -29,6 +29,8 @@ class Account(PredeployedContractWrapper):
         initial_balance: int,
         account_class_wrapper: CompiledClassWrapper,
         index: Optional[int] = None,
+        address: Optional[int] = None,
+        initial_nonce: int = 0,
     ):
         self.starknet_wrapper = starknet_wrapper
         self.private_key = private_key
@@ -37,14 +39,15 @@ class Account(PredeployedContractWrapper):
         self.class_hash = account_class_wrapper.hash
 
         # salt and class_hash have frozen values that make the constructor_calldata
-        # the only thing that affects the account address
-        self.address = calculate_contract_address_from_hash(
+        # the only thing that affects the account address, unless it is provided
+        self.address = address or calculate_contract_address_from_hash(
             salt=20,
             class_hash=0x3FCBF77B28C96F4F2FB5BD2D176AB083A12A5E123ADEB0DE955D7EE228C9854,
             constructor_calldata=[public_key],
             deployer_address=0,
         )
         self.initial_balance = initial_balance
+        self.initial_nonce = initial_nonce
 
         self.__index = index
         """Index used when logging/displaying account to user on startup"""
@@ -70,6 +73,11 @@ class Account(PredeployedContractWrapper):
 
         await set_balance(starknet.state, self.address, self.initial_balance)
 
+        if self.initial_nonce:
+            # CachedState only supports incrementing the nonce; set it at once instead
+            # pylint: disable=protected-access
+            starknet.state.state.cache._nonce_writes[self.address] = self.initial_nonce
+
     def print(self):
         print(f"Account #{self.__index}:")
         print(f"Address: {hex(self.address)}")
@@ -116,12 +116,17 @@ def _get_signature(message_hash: int, private_key: int) -> Tuple[str, str]:
     return [str(sig_r), str(sig_s)]
 
 
+def get_balance_key(address: int) -> int:
+    """Get the key under which the fee token stores the balance of `address`"""
+    return pedersen_hash(get_selector_from_name("ERC20_balances"), address)
+
+
 async def set_balance(state: StarknetState, address: int, balance: int):
     """Modify `state` so that `address` has `balance`"""
 
     fee_token_address = state.general_config.fee_token_address
 
-    balance_key = pedersen_hash(get_selector_from_name("ERC20_balances"), address)
+    balance_key = get_balance_key(address)
     balance_uint256 = Uint256.from_felt(balance)
 
     await state.state.set_storage_at(
@@ -21,11 +21,11 @@ class Accounts:
         self.starknet_wrapper = starknet_wrapper
         self.__n_accounts = starknet_wrapper.config.accounts
         self.__initial_balance = starknet_wrapper.config.initial_balance
+        self.__initial_nonce = starknet_wrapper.config.account_initial_nonce
         self.__account_class_wrapper = starknet_wrapper.config.account_class
+        self.__addresses = starknet_wrapper.config.predeployed_account_addresses
 
         self.__seed = starknet_wrapper.config.seed
-        if self.__seed is None:
-            self.__seed = random.getrandbits(32)
 
         self.list = []
 
@@ -64,8 +64,10 @@ class Accounts:
                     private_key=private_key,
                     public_key=public_key,
                     initial_balance=self.__initial_balance,
+                    initial_nonce=self.__initial_nonce,
                     account_class_wrapper=self.__account_class_wrapper,
                     index=i,
+                    address=self.__addresses[i] if self.__addresses else None,
                 )
             )
 
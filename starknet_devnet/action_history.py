@@ -0,0 +1,28 @@
+"""
+History of Devnet-specific actions.
+"""
+
+import time
+from collections import deque
+from typing import List
+
+
+class ActionHistory:
+    """Keeps a bounded number of the most recent Devnet-specific actions"""
+
+    def __init__(self, size: int):
+        self.__actions = deque(maxlen=size)
+
+    def record(self, endpoint: str, params: dict):
+        """Record an action, discarding the oldest one if the limit is reached"""
+        self.__actions.append(
+            {
+                "endpoint": endpoint,
+                "params": params,
+                "timestamp": time.time(),
+            }
+        )
+
+    def get_actions(self) -> List[dict]:
+        """Returns the recorded actions, oldest first"""
+        return list(self.__actions)
@@ -18,21 +18,43 @@ def now() -> int:
 class BlockInfoGenerator:
     """Generator of BlockInfo objects with the correct timestamp"""
 
-    def __init__(self, start_time: int = None, gas_price: int = 0):
+    def __init__(
+        self,
+        start_time: int = None,
+        gas_price: int = 0,
+        block_time_increment: int = None,
+    ):
         self.block_timestamp_offset = 0
+        self.pending_time_increase = 0
         self.next_block_start_time = start_time
         self.gas_price = gas_price
+        self.block_time_increment = block_time_increment
+        self.last_block_timestamp = None
 
     def next_block(self, block_info: BlockInfo, general_config: StarknetGeneralConfig):
         """
         Returns the next block info with the correct timestamp
         """
-        if self.next_block_start_time is None:
-            block_timestamp = now() + self.block_timestamp_offset
-        else:
+        if self.next_block_start_time is not None:
             block_timestamp = self.next_block_start_time
-            self.block_timestamp_offset = block_timestamp - now()
+            if self.block_time_increment is None:
+                self.block_timestamp_offset = block_timestamp - now()
+            else:
+                self.pending_time_increase = 0
             self.next_block_start_time = None
+        elif self.block_time_increment is None:
+            block_timestamp = now() + self.block_timestamp_offset
+        else:
+            # wall clock is only used for the first block;
+            # an increase of time is applied only once
+            block_timestamp = (
+                now()
+                if self.last_block_timestamp is None
+                else self.last_block_timestamp + self.block_time_increment
+            ) + self.pending_time_increase
+            self.pending_time_increase = 0
+
+        self.last_block_timestamp = block_timestamp
 
         return BlockInfo(
             gas_price=self.gas_price,
@@ -44,9 +66,13 @@ class BlockInfoGenerator:
 
     def increase_time(self, time_s: int):
         """
-        Increases block timestamp offset
+        Increases block timestamp offset; if blocks have a fixed time increment,
+        only the timestamp of the next block is increased
         """
-        self.block_timestamp_offset += time_s
+        if self.block_time_increment is None:
+            self.block_timestamp_offset += time_s
+        else:
+            self.pending_time_increase += time_s
 
     def set_next_block_time(self, time_s: int):
         """
@@ -75,24 +75,42 @@ def _parse_block_number(raw: Optional[Union[int, str]]) -> BlockIdentifier:
 class DevnetBlocks:
     """This class is used to store the generated blocks of the devnet."""
 
-    def __init__(self, origin: Origin, lite=False) -> None:
+    def __init__(
+        self,
+        origin: Origin,
+        lite=False,
+        instant_l1_acceptance=False,
+        genesis_parent_hash=0,
+        state_archive_limit: Optional[int] = None,
+        starting_block_number=0,
+    ) -> None:
         self.origin = origin
         self.lite = lite
+        self.instant_l1_acceptance = instant_l1_acceptance
+        self.genesis_parent_hash = genesis_parent_hash
+        self.starting_block_number = starting_block_number
         self.__hash2block: Dict[int, StarknetBlock] = {}
         self.__state_updates: Dict[int, BlockStateUpdate] = {}
         self.__num2hash: Dict[int, int] = {}
         self.__pending_block: StarknetBlock = None
         self.__pending_state_update: BlockStateUpdate = None
         self.__pending_signatures: Sequence[List[int]] = None
-        self.__state_archive = MemoryStateArchive()
+        self.__state_archive = MemoryStateArchive(limit=state_archive_limit)
 
     async def get_last_block(self) -> StarknetBlock:
         """Returns the last block stored so far."""
         return await self.get_by_number(self.get_number_of_accepted_blocks() - 1)
 
     def get_number_of_accepted_blocks(self) -> int:
-        """Returns the number of not aborted blocks."""
-        return len(self.__num2hash) + self.origin.get_number_of_blocks()
+        """
+        Returns the number of not aborted blocks.
+        Numbers skipped by a non-zero starting block number are counted as blocks.
+        """
+        return (
+            len(self.__num2hash)
+            + self.origin.get_number_of_blocks()
+            + self.starting_block_number
+        )
 
     def get_next_block_number(self) -> int:
         """Returns the block_number of the next block"""
@@ -106,6 +124,14 @@ class DevnetBlocks:
             raise StarknetDevnetException(
                 code=StarkErrorCode.MALFORMED_REQUEST, message=message
             )
+        if block_number < self.starting_block_number:
+            message = (
+                f"Block number {block_number} precedes the starting block number "
+                f"{self.starting_block_number}."
+            )
+            raise StarknetDevnetException(
+                code=StarknetErrorCode.BLOCK_NOT_FOUND, message=message
+            )
         number_of_accepted_blocks = self.get_number_of_accepted_blocks()
         if block_number >= number_of_accepted_blocks:
             message = f"Block number too high. There are currently {number_of_accepted_blocks} blocks; got: {block_number}."
@@ -145,6 +171,26 @@ class DevnetBlocks:
 
         return await self.origin.get_block_by_hash(block_hash)
 
+    def get_by_timestamp(self, timestamp: int) -> StarknetBlock:
+        """
+        Returns the block with the greatest timestamp not exceeding `timestamp`.
+        Of blocks with equal timestamps, the latest one is returned.
+        Blocks of the forking origin are not searched.
+        """
+        # timestamps can decrease if the time is set back, so all blocks are searched
+        candidates = [
+            self.__hash2block[block_hash]
+            for block_hash in self.__num2hash.values()
+            if self.__hash2block[block_hash].timestamp <= timestamp
+        ]
+        if not candidates:
+            raise StarknetDevnetException(
+                code=StarknetErrorCode.BLOCK_NOT_FOUND,
+                message=f"No block with timestamp lower than or equal to {timestamp}.",
+            )
+
+        return max(candidates, key=lambda block: (block.timestamp, block.block_number))
+
     async def get_state_update(
         self, block_hash: str = None, block_number: Any = None
     ) -> BlockStateUpdate:
@@ -186,6 +232,10 @@ class DevnetBlocks:
             or await self.origin.get_state_update()
         )
 
+    def get_pending_state_update(self) -> Optional[BlockStateUpdate]:
+        """Returns the state update of the pending block, if there is one"""
+        return self.__pending_state_update
+
     async def generate_pending(
         self,
         transactions: List[DevnetTransaction],
@@ -202,8 +252,8 @@ class DevnetBlocks:
         transaction_receipts = tuple(tx.get_execution() for tx in transactions or ())
 
         block_number = self.get_number_of_accepted_blocks()
-        if block_number == 0:
-            parent_block_hash = 0
+        if block_number == self.starting_block_number:
+            parent_block_hash = self.genesis_parent_hash
         else:
             last_block = await self.get_last_block()
             parent_block_hash = last_block.block_hash
@@ -275,7 +325,11 @@ class DevnetBlocks:
 
         block_dict = self.__pending_block.dump()
 
-        block_dict["status"] = BlockStatus.ACCEPTED_ON_L2.name
+        block_dict["status"] = (
+            BlockStatus.ACCEPTED_ON_L1
+            if self.instant_l1_acceptance
+            else BlockStatus.ACCEPTED_ON_L2
+        ).name
         state_root = DUMMY_STATE_ROOT
         block_dict["state_root"] = hex(state_root)
 
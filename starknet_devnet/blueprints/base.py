@@ -1,11 +1,27 @@
 """
 Base routes
 """
+from typing import List
+from urllib.parse import urlparse
+
 from flask import Blueprint, Response, jsonify, request
+from starkware.cairo.lang.cairo_constants import DEFAULT_PRIME
+from starkware.cairo.lang.vm.crypto import pedersen_hash
+from starkware.starknet.core.os.contract_address.contract_address import (
+    calculate_contract_address_from_hash,
+)
 from starkware.starkware_utils.error_handling import StarkErrorCode
+from werkzeug.datastructures import MultiDict
 
+from starknet_devnet.blueprints.shared import get_block_id
+from starknet_devnet.constants import (
+    FORK_ORIGIN_HEALTH_CHECK_TIMEOUT,
+    MAX_CREATED_BLOCKS_COUNT,
+)
 from starknet_devnet.fee_token import FeeToken
+from starknet_devnet.origin import get_forwarded_queries
 from starknet_devnet.state import state
+from starknet_devnet.udc import UDC
 from starknet_devnet.util import (
     StarknetDevnetException,
     check_valid_dump_path,
@@ -76,6 +92,37 @@ def is_alive():
     return "Alive!!!"
 
 
+@base.route("/health", methods=["GET"])
+async def health():
+    """Health check reporting the reachability of the forking origin"""
+    check_origin = request.args.get("checkOrigin", "true")
+    if check_origin not in ["true", "false"]:
+        raise StarknetDevnetException(
+            code=StarkErrorCode.MALFORMED_REQUEST,
+            message=f"checkOrigin value must be true or false; got: {check_origin}.",
+        )
+
+    starknet_wrapper = state.starknet_wrapper
+    fork_origin_reachable = None
+    if starknet_wrapper.config.fork_network and check_origin == "true":
+        fork_origin_reachable = await starknet_wrapper.origin.is_reachable(
+            timeout=FORK_ORIGIN_HEALTH_CHECK_TIMEOUT
+        )
+
+    latest_block = await starknet_wrapper.blocks.get_last_block()
+    healthy = fork_origin_reachable is not False
+    return (
+        jsonify(
+            {
+                "status": "healthy" if healthy else "unhealthy",
+                "fork_origin_reachable": fork_origin_reachable,
+                "latest_block_number": latest_block.block_number,
+            }
+        ),
+        200 if healthy else 503,
+    )
+
+
 @base.route("/restart", methods=["POST"])
 async def restart():
     """Restart the starknet_wrapper"""
@@ -126,6 +173,64 @@ def load():
     return Response(status=200)
 
 
+def extract_felt(request_json, prop_name: str) -> int:
+    """Expects `prop_name` from `request_json` to be a hex string of a field element"""
+    value = hex_converter(request_json, prop_name)
+    if not 0 <= value < DEFAULT_PRIME:
+        raise StarknetDevnetException(
+            code=StarkErrorCode.MALFORMED_REQUEST,
+            status_code=400,
+            message=f"{prop_name} value must be smaller than the field prime.",
+        )
+
+    return value
+
+
+def parse_hex_strings(values) -> List[int]:
+    """Parse a list of hex strings to ints"""
+    if not isinstance(values, list):
+        raise TypeError(f"Expected a list, got: {values}")
+
+    return [parse_hex_string(value) for value in values]
+
+
+@base.route("/compute_contract_address", methods=["POST"])
+def compute_contract_address():
+    """Compute the address of a contract deployed with the provided arguments"""
+    request_json = request.json or {}
+    class_hash = extract_felt(request_json, "classHash")
+    salt = extract_felt(request_json, "salt")
+    constructor_calldata = hex_converter(
+        request_json, "constructorCalldata", convert=parse_hex_strings
+    )
+    deploy_from_zero = request_json.get("deployFromZero", False)
+    unique = request_json.get("unique", False)
+    if deploy_from_zero and unique:
+        raise StarknetDevnetException(
+            code=StarkErrorCode.MALFORMED_REQUEST,
+            message="deployFromZero and unique cannot both be true.",
+            status_code=400,
+        )
+
+    if deploy_from_zero:
+        deployer_address = 0
+    else:
+        deployer_address = extract_felt(request_json, "deployerAddress")
+
+    if unique:
+        # the way UDC deploys with unique=1, where the caller is the deployerAddress
+        salt = pedersen_hash(deployer_address, salt)
+        deployer_address = UDC.ADDRESS
+
+    contract_address = calculate_contract_address_from_hash(
+        salt=salt,
+        class_hash=class_hash,
+        constructor_calldata=constructor_calldata,
+        deployer_address=deployer_address,
+    )
+    return jsonify({"contract_address": hex(contract_address)})
+
+
 @base.route("/increase_time", methods=["POST"])
 @log_request()
 async def increase_time():
@@ -138,7 +243,11 @@ async def increase_time():
         state.starknet_wrapper.increase_block_time(time_s)
         block = await state.starknet_wrapper.generate_latest_block()
         return jsonify(
-            {"timestamp_increased_by": time_s, "block_hash": hex(block.block_hash)}
+            {
+                "timestamp_increased_by": time_s,
+                "block_hash": hex(block.block_hash),
+                "block_timestamp": block.timestamp,
+            }
         )
 
     raise StarknetDevnetException(
@@ -168,20 +277,214 @@ async def set_time():
     )
 
 
+@base.route("/block_by_timestamp", methods=["GET"])
+def get_block_by_timestamp():
+    """Get the block with the greatest timestamp not exceeding the provided one"""
+    timestamp = extract_optional_non_negative_arg(request.args, "timestamp")
+    if timestamp is None:
+        raise StarknetDevnetException(
+            code=StarkErrorCode.MALFORMED_REQUEST,
+            message="timestamp value must be provided.",
+        )
+
+    block = state.starknet_wrapper.blocks.get_by_timestamp(timestamp)
+    return jsonify(block.dump())
+
+
+@base.route("/set_block_number", methods=["POST"])
+@log_request()
+async def set_block_number():
+    """Sets the block number used when executing the next transaction"""
+    request_dict = request.json or {}
+    block_number = extract_positive(request_dict, "blockNumber")
+
+    state.starknet_wrapper.set_block_number(block_number)
+    return jsonify({"next_block_number": block_number})
+
+
+@base.route("/set_storage_at", methods=["POST"])
+@log_request()
+async def set_storage_at():
+    """Writes a value to contract storage without executing a transaction"""
+    request_json = request.json or {}
+    contract_address = extract_felt(request_json, "contractAddress")
+    key = extract_felt(request_json, "key")
+    value = extract_felt(request_json, "value")
+
+    block = await state.starknet_wrapper.set_storage_at(contract_address, key, value)
+    return jsonify({"block_hash": hex(block.block_hash) if block else None})
+
+
+@base.route("/set_nonce", methods=["POST"])
+@log_request()
+async def set_nonce():
+    """Sets the nonce of a deployed contract without executing a transaction"""
+    request_json = request.json or {}
+    contract_address = extract_felt(request_json, "contractAddress")
+    nonce = extract_felt(request_json, "nonce")
+
+    block = await state.starknet_wrapper.set_nonce(contract_address, nonce)
+    return jsonify({"block_hash": hex(block.block_hash) if block else None})
+
+
+@base.route("/replace_class", methods=["POST"])
+@log_request()
+async def replace_class():
+    """Replaces the class of a deployed contract without executing a transaction"""
+    request_json = request.json or {}
+    contract_address = extract_felt(request_json, "contractAddress")
+    class_hash = extract_felt(request_json, "classHash")
+
+    block = await state.starknet_wrapper.replace_class(contract_address, class_hash)
+    return jsonify({"block_hash": hex(block.block_hash) if block else None})
+
+
 @base.route("/account_balance", methods=["GET"])
 async def get_balance():
-    """Gets balance for the address"""
+    """Gets balance for the address, optionally at a specific block"""
     address = request.args.get("address", type=lambda x: int(x, 16))
+    block_id = None
+    if "blockNumber" in request.args or "blockHash" in request.args:
+        block_id = get_block_id(request.args)
 
-    balance = await state.starknet_wrapper.fee_token.get_balance(address)
+    balance = await state.starknet_wrapper.fee_token.get_balance(address, block_id)
     return jsonify({"amount": balance, "unit": "wei"})
 
 
+@base.route("/sequencer_balance", methods=["GET"])
+async def get_sequencer_balance():
+    """Gets the balance of the sequencer, i.e. the fees collected so far"""
+    starknet_wrapper = state.starknet_wrapper
+    sequencer_address = starknet_wrapper.get_state().general_config.sequencer_address
+
+    balance = await starknet_wrapper.fee_token.get_balance(sequencer_address)
+    return jsonify(
+        {"address": hex(sequencer_address), "amount": balance, "unit": "wei"}
+    )
+
+
+def extract_optional_non_negative_arg(args: MultiDict, prop_name: str):
+    """Expects `prop_name` from query `args` to be a non-negative integer, if present"""
+    value = args.get(prop_name)
+    if value is None:
+        return None
+
+    if not value.isdigit():
+        raise StarknetDevnetException(
+            code=StarkErrorCode.MALFORMED_REQUEST,
+            message=f"{prop_name} value must be a non-negative integer; got: {value}.",
+            status_code=400,
+        )
+
+    return int(value)
+
+
+def extract_block_range(args: MultiDict):
+    """Extract the optional inclusive range of block numbers from query `args`"""
+    from_block = extract_optional_non_negative_arg(args, "fromBlock")
+    to_block = extract_optional_non_negative_arg(args, "toBlock")
+    if from_block is not None and to_block is not None and from_block > to_block:
+        raise StarknetDevnetException(
+            code=StarkErrorCode.MALFORMED_REQUEST,
+            message=f"fromBlock ({from_block}) must not exceed toBlock ({to_block}).",
+            status_code=400,
+        )
+
+    return from_block, to_block
+
+
 @base.route("/predeployed_accounts", methods=["GET"])
-def get_predeployed_accounts():
-    """Get predeployed accounts"""
-    accounts = state.starknet_wrapper.accounts
-    return jsonify([account.to_json() for account in accounts])
+async def get_predeployed_accounts():
+    """Get predeployed accounts, optionally filtered by balance and paginated"""
+    accounts = state.starknet_wrapper.accounts.list
+    min_balance = extract_optional_non_negative_arg(request.args, "minBalance")
+    skip = extract_optional_non_negative_arg(request.args, "skip")
+    limit = extract_optional_non_negative_arg(request.args, "limit")
+
+    if min_balance is None:
+        accounts_json = [account.to_json() for account in accounts]
+    else:
+        accounts_json = []
+        for account in accounts:
+            balance = await state.starknet_wrapper.fee_token.get_balance(
+                account.address
+            )
+            if balance >= min_balance:
+                accounts_json.append({**account.to_json(), "balance": balance})
+
+    if skip is None and limit is None:
+        return jsonify(accounts_json)
+
+    start = skip or 0
+    end = None if limit is None else start + limit
+    return jsonify({"accounts": accounts_json[start:end], "total": len(accounts_json)})
+
+
+@base.route("/contract_transactions", methods=["GET"])
+def get_contract_transactions():
+    """Get hashes of transactions which touched the contract"""
+    contract_address = hex_converter(request.args, "contractAddress")
+    from_block, to_block = extract_block_range(request.args)
+
+    tx_hashes = state.starknet_wrapper.transactions.get_transaction_hashes_by_contract(
+        contract_address, from_block=from_block, to_block=to_block
+    )
+    return jsonify({"transaction_hashes": [hex(tx_hash) for tx_hash in tx_hashes]})
+
+
+@base.route("/class_hashes", methods=["GET"])
+def get_class_hashes():
+    """Get hashes of classes declared on Devnet, optionally up to a block"""
+    block_number = extract_optional_non_negative_arg(request.args, "blockNumber")
+
+    class_hashes = state.starknet_wrapper.transactions.get_declared_class_hashes(
+        to_block=block_number
+    )
+    return jsonify({"class_hashes": [hex(class_hash) for class_hash in class_hashes]})
+
+
+@base.route("/contract_storage", methods=["GET"])
+async def get_contract_storage():
+    """Get the non-zero storage of a contract, optionally at a block"""
+    contract_address = hex_converter(request.args, "contractAddress")
+    block_id = get_block_id(request.args)
+
+    storage = await state.starknet_wrapper.get_contract_storage(
+        contract_address, block_id
+    )
+    return jsonify(
+        {
+            "storage": {
+                hex(key): hex(value) for key, value in sorted(storage.items())
+            },
+            # storage of the forking origin is only known if accessed
+            "partial": bool(state.starknet_wrapper.config.fork_network),
+        }
+    )
+
+
+@base.route("/account_fees_paid", methods=["GET"])
+def get_account_fees_paid():
+    """Get the sum of fees paid by transactions sent from the account"""
+    address = hex_converter(request.args, "address")
+    from_block, to_block = extract_block_range(request.args)
+
+    fees_paid = state.starknet_wrapper.transactions.get_fees_paid(
+        address, from_block=from_block, to_block=to_block
+    )
+    return jsonify({"amount": fees_paid, "unit": "wei"})
+
+
+@base.route("/transaction_execution_info", methods=["GET"])
+def get_transaction_execution_info():
+    """Get the raw execution info of a transaction executed by Devnet"""
+    transaction_hash = request.args.get("transactionHash")
+    execution_info = state.starknet_wrapper.transactions.get_transaction_execution_info(
+        transaction_hash
+    )
+    return Response(
+        response=execution_info.dumps(), status=200, mimetype="application/json"
+    )
 
 
 @base.route("/fee_token", methods=["GET"])
@@ -202,12 +505,56 @@ async def mint():
     amount = extract_positive(request_json, "amount")
     is_lite = request_json.get("lite", False)
 
-    tx_hash = await state.starknet_wrapper.fee_token.mint(
-        to_address=address, amount=amount, lite=is_lite
-    )
+    token = state.starknet_wrapper.fee_token
+    if "contractAddress" in request_json:
+        token_address = hex_converter(request_json, "contractAddress")
+        token = state.starknet_wrapper.get_erc20(token_address)
+
+    tx_hash = await token.mint(to_address=address, amount=amount, lite=is_lite)
+
+    new_balance = await token.get_balance(address)
+    unit = "wei" if token.is_fee_token else token.symbol
+    return jsonify({"new_balance": new_balance, "unit": unit, "tx_hash": tx_hash})
+
+
+@base.route("/mint_multiple", methods=["POST"])
+@log_request()
+async def mint_multiple():
+    """Mint tokens to multiple addresses; all entries are validated before minting"""
+    request_json = request.json or {}
+    mints = request_json.get("mints")
+    if not isinstance(mints, list) or not all(isinstance(m, dict) for m in mints):
+        raise StarknetDevnetException(
+            code=StarkErrorCode.MALFORMED_REQUEST,
+            status_code=400,
+            message="mints value must be a list of objects with address and amount.",
+        )
+    is_lite = request_json.get("lite", False)
+
+    parsed_mints = [
+        (hex_converter(mint_json, "address"), extract_positive(mint_json, "amount"))
+        for mint_json in mints
+    ]
 
-    new_balance = await state.starknet_wrapper.fee_token.get_balance(address)
-    return jsonify({"new_balance": new_balance, "unit": "wei", "tx_hash": tx_hash})
+    fee_token = state.starknet_wrapper.fee_token
+    results = []
+    for address, amount in parsed_mints:
+        tx_hash = await fee_token.mint(to_address=address, amount=amount, lite=is_lite)
+        new_balance = await fee_token.get_balance(address)
+        results.append({"new_balance": new_balance, "unit": "wei", "tx_hash": tx_hash})
+
+    return jsonify({"mints": results})
+
+
+@base.route("/set_next_transaction_hash", methods=["POST"])
+@log_request()
+async def set_next_transaction_hash():
+    """Override the hash of the next submitted transaction"""
+    request_json = request.json or {}
+    tx_hash = hex_converter(request_json, "transactionHash")
+
+    state.starknet_wrapper.set_next_transaction_hash(tx_hash)
+    return jsonify({"next_transaction_hash": hex(tx_hash)})
 
 
 @base.route("/create_block", methods=["POST"])
@@ -218,19 +565,121 @@ async def create_block():
     return jsonify({"block_hash": hex(block.block_hash)})
 
 
+@base.route("/create_blocks", methods=["POST"])
+@log_request()
+async def create_blocks():
+    """Create `count` blocks; only the first one contains the pending transactions."""
+    request_dict = request.json or {}
+    count = extract_positive(request_dict, "count")
+    if not 1 <= count <= MAX_CREATED_BLOCKS_COUNT:
+        raise StarknetDevnetException(
+            code=StarkErrorCode.MALFORMED_REQUEST,
+            message=f"count must be between 1 and {MAX_CREATED_BLOCKS_COUNT}.",
+        )
+
+    blocks = []
+    for _ in range(count):
+        blocks.append(await state.starknet_wrapper.generate_latest_block())
+
+    return jsonify(
+        {
+            "first_block_hash": hex(blocks[0].block_hash),
+            "last_block_hash": hex(blocks[-1].block_hash),
+        }
+    )
+
+
+@base.route("/set_block_generation_mode", methods=["POST"])
+@log_request()
+async def set_block_generation_mode():
+    """Switch between generating a block with each transaction and on demand"""
+    request_json = request.json or {}
+    mode = request_json.get("mode")
+    if mode not in ("transaction", "demand"):
+        raise StarknetDevnetException(
+            code=StarkErrorCode.MALFORMED_REQUEST,
+            message=f"mode must be one of: transaction, demand; got: {mode}.",
+        )
+
+    block = await state.starknet_wrapper.set_blocks_on_demand(mode == "demand")
+    block_hash = hex(block.block_hash) if block else None
+    return jsonify({"block_generation_on": mode, "block_hash": block_hash})
+
+
+@base.route("/config", methods=["GET"])
+def get_config():
+    """Get the configuration options relevant to clients of Devnet"""
+    config = state.starknet_wrapper.config
+    block_generation_on = "demand" if config.blocks_on_demand else "transaction"
+    return jsonify(
+        {
+            "block_generation_on": block_generation_on,
+            "lite_mode": config.lite_mode,
+            "instant_l1_acceptance": config.instant_l1_acceptance,
+            "chain_id": config.chain_id.name,
+            "sequencer_address": hex(config.sequencer_address),
+            "predeployed_erc20s": [
+                {"name": name, "symbol": symbol, "address": hex(address)}
+                for name, symbol, address in config.predeployed_erc20s
+            ],
+        }
+    )
+
+
+@base.route("/action_history", methods=["GET"])
+def get_action_history():
+    """Get the most recent Devnet-specific actions"""
+    actions = state.starknet_wrapper.action_history.get_actions()
+    return jsonify({"actions": actions})
+
+
+def redact_credentials(url: str) -> str:
+    """Hide the user info (e.g. an API key) of the url"""
+    parsed_url = urlparse(url)
+    if parsed_url.username is None and parsed_url.password is None:
+        return url
+
+    host = parsed_url.netloc.rsplit("@", 1)[1]
+    return parsed_url._replace(netloc=f"***@{host}").geturl()
+
+
 @base.route("/fork_status", methods=["GET"])
 async def fork_status():
     """Get fork status"""
     config = state.starknet_wrapper.config
     if config.fork_network:
-        return jsonify({"url": config.fork_network.url, "block": config.fork_block})
+        return jsonify(
+            {
+                "url": redact_credentials(config.fork_network.url),
+                "block": config.fork_block,
+                "forwarded_queries": get_forwarded_queries(),
+            }
+        )
     return jsonify({})
 
 
 @base.route("/abort_blocks", methods=["POST"])
 async def abort_blocks():
-    """Abort blocks and transactions from given block hash to last block."""
+    """
+    Abort blocks and transactions from given block hash to last block.
+    Alternatively, abort the `count` latest blocks.
+    """
     request_json = request.json or {}
+    if "count" in request_json:
+        if "startingBlockHash" in request_json:
+            raise StarknetDevnetException(
+                code=StarkErrorCode.MALFORMED_REQUEST,
+                message="Only one of {startingBlockHash,count} can be provided.",
+            )
+        count = extract_positive(request_json, "count")
+        if count < 1:
+            raise StarknetDevnetException(
+                code=StarkErrorCode.MALFORMED_REQUEST,
+                message="count must be at least 1.",
+            )
+        aborted_blocks = await state.starknet_wrapper.abort_latest_blocks(count)
+        return jsonify({"aborted": aborted_blocks})
+
     starting_block = await state.starknet_wrapper.blocks.get_by_hash(
         hex(hex_converter(request_json, "startingBlockHash"))
     )
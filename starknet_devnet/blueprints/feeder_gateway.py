@@ -2,7 +2,7 @@
 Feeder gateway routes.
 """
 
-from typing import Type
+from typing import Optional, Type
 
 from flask import Blueprint, Response, jsonify, request
 from marshmallow import ValidationError
@@ -11,8 +11,6 @@ from starkware.starknet.services.api.feeder_gateway.request_objects import (
     CallL1Handler,
 )
 from starkware.starknet.services.api.feeder_gateway.response_objects import (
-    LATEST_BLOCK_ID,
-    PENDING_BLOCK_ID,
     BlockTransactionTraces,
     StarknetBlock,
     TransactionSimulationInfo,
@@ -26,7 +24,6 @@ from starkware.starkware_utils.error_handling import StarkErrorCode
 from starkware.starkware_utils.validated_dataclass import ValidatedMarshmallowDataclass
 from werkzeug.datastructures import MultiDict
 
-from starknet_devnet.blueprints.rpc.structures.types import BlockId
 from starknet_devnet.state import state
 from starknet_devnet.util import (
     StarknetDevnetException,
@@ -34,6 +31,8 @@ from starknet_devnet.util import (
     parse_hex_string,
 )
 
+from .shared import get_block_id
+
 feeder_gateway = Blueprint("feeder_gateway", __name__, url_prefix="/feeder_gateway")
 
 
@@ -102,24 +101,6 @@ async def _get_block_transaction_traces(block: StarknetBlock):
     return BlockTransactionTraces.load({"traces": traces})
 
 
-def _get_block_id(args: MultiDict) -> BlockId:
-    block_number = args.get("blockNumber")
-    block_hash = args.get("blockHash")
-
-    if block_number is None and block_hash is None:
-        return "latest"
-
-    if block_number is None:
-        # there is some hash
-        return {"block_hash": block_hash}
-
-    if block_number in [PENDING_BLOCK_ID, LATEST_BLOCK_ID]:
-        return block_number
-
-    # there is some number and it should be an integer
-    return {"block_number": block_number}
-
-
 def _get_skip_validate(args: MultiDict) -> bool:
     skip_validate = args.get("skipValidate")
 
@@ -139,6 +120,22 @@ def _get_skip_validate(args: MultiDict) -> bool:
     )
 
 
+def _get_gas_price(args: MultiDict) -> Optional[int]:
+    gas_price = args.get("gasPrice")
+
+    # default case (user did not specify)
+    if gas_price is None:
+        return None
+
+    if not gas_price.isdigit():
+        raise StarknetDevnetException(
+            code=StarkErrorCode.MALFORMED_REQUEST,
+            message=f"Invalid value for gasPrice: {gas_price}. Should be a non-negative integer.",
+        )
+
+    return int(gas_price)
+
+
 @feeder_gateway.route("/get_contract_addresses", methods=["GET"])
 def get_contract_addresses():
     """Endpoint that returns an object containing the addresses of key system components."""
@@ -151,7 +148,7 @@ async def call_contract():
     Endpoint for receiving calls (not invokes) of contract functions.
     """
 
-    block_id = _get_block_id(request.args)
+    block_id = get_block_id(request.args)
     data = request.get_data()  # better than request.data in some edge cases
 
     try:
@@ -194,7 +191,7 @@ async def get_code():
     Returns the ABI and bytecode of the contract whose contractAddress is provided.
     """
 
-    block_id = _get_block_id(request.args)
+    block_id = get_block_id(request.args)
 
     contract_address = request.args.get("contractAddress", type=parse_hex_string)
     code_dict = await state.starknet_wrapper.get_code(contract_address, block_id)
@@ -206,7 +203,7 @@ async def get_full_contract():
     """
     Returns the contract class of the contract whose contractAddress is provided.
     """
-    block_id = _get_block_id(request.args)
+    block_id = get_block_id(request.args)
     contract_address = request.args.get("contractAddress", type=parse_hex_string)
     contract_class = await state.starknet_wrapper.get_class_by_address(
         contract_address, block_id
@@ -254,7 +251,7 @@ async def get_compiled_class_by_hash():
 @feeder_gateway.route("/get_storage_at", methods=["GET"])
 async def get_storage_at():
     """Endpoint for returning the storage identified by `key` from the contract at"""
-    block_id = _get_block_id(request.args)
+    block_id = get_block_id(request.args)
 
     contract_address = request.args.get("contractAddress", type=parse_hex_string)
     key = validate_int(request.args, "key")
@@ -351,11 +348,12 @@ async def estimate_fee():
     except StarknetDevnetException:
         transaction = validate_request(data, InvokeFunction)  # version 0
 
-    block_id = _get_block_id(request.args)
+    block_id = get_block_id(request.args)
     skip_validate = _get_skip_validate(request.args)
+    gas_price = _get_gas_price(request.args)
 
     _, fee_response = await state.starknet_wrapper.calculate_trace_and_fee(
-        transaction, skip_validate=skip_validate, block_id=block_id
+        transaction, skip_validate=skip_validate, block_id=block_id, gas_price=gas_price
     )
     return jsonify(fee_response)
 
@@ -371,13 +369,15 @@ async def estimate_fee_bulk():
         # version 0
         transactions = validate_request(request.get_data(), InvokeFunction, many=True)
 
-    block_id = _get_block_id(request.args)
+    block_id = get_block_id(request.args)
     skip_validate = _get_skip_validate(request.args)
+    gas_price = _get_gas_price(request.args)
 
     _, fee_responses = await state.starknet_wrapper.calculate_traces_and_fees(
         transactions,
         block_id=block_id,
         skip_validate=skip_validate,
+        gas_price=gas_price,
     )
     return jsonify(fee_responses)
 
@@ -386,13 +386,15 @@ async def estimate_fee_bulk():
 async def simulate_transaction():
     """Returns the estimated fee for a transaction."""
     transaction = validate_request(request.get_data(), AccountTransaction)
-    block_id = _get_block_id(request.args)
+    block_id = get_block_id(request.args)
     skip_validate = _get_skip_validate(request.args)
+    gas_price = _get_gas_price(request.args)
 
     trace, fee_response = await state.starknet_wrapper.calculate_trace_and_fee(
         transaction,
         block_id=block_id,
         skip_validate=skip_validate,
+        gas_price=gas_price,
     )
 
     simulation_info = TransactionSimulationInfo(
@@ -406,7 +408,7 @@ async def simulate_transaction():
 async def get_nonce():
     """Returns the nonce of the contract whose contractAddress is provided"""
 
-    block_id = _get_block_id(request.args)
+    block_id = get_block_id(request.args)
     contract_address = request.args.get("contractAddress", type=parse_hex_string)
     nonce = await state.starknet_wrapper.get_nonce(contract_address, block_id)
 
@@ -417,7 +419,7 @@ async def get_nonce():
 async def estimate_message_fee():
     """Message fee estimation endpoint"""
 
-    block_id = _get_block_id(request.args)
+    block_id = get_block_id(request.args)
 
     call = validate_request(request.get_data(), CallL1Handler)
     fee_estimation = await state.starknet_wrapper.estimate_message_fee(call, block_id)
@@ -6,6 +6,7 @@ from flask import Blueprint, jsonify, request
 from starkware.starknet.definitions.error_codes import StarknetErrorCode
 from starkware.starknet.definitions.transaction_type import TransactionType
 from starkware.starkware_utils.error_handling import StarkErrorCode
+from werkzeug.datastructures import MultiDict
 
 from starknet_devnet.devnet_config import DumpOn
 from starknet_devnet.state import state
@@ -16,6 +17,23 @@ from .shared import validate_transaction
 gateway = Blueprint("gateway", __name__, url_prefix="/gateway")
 
 
+def _get_dry_run(args: MultiDict) -> bool:
+    dry_run = args.get("dryRun")
+
+    if dry_run == "true":
+        return True
+
+    # default case (user did not specify)
+    if dry_run in ("false", None):
+        return False
+
+    raise StarknetDevnetException(
+        code=StarkErrorCode.MALFORMED_REQUEST,
+        message=f"Invalid value for dryRun: {dry_run}. Should be true or false.",
+        status_code=400,
+    )
+
+
 @gateway.route("/add_transaction", methods=["POST"])
 @log_request()
 async def add_transaction():
@@ -24,13 +42,35 @@ async def add_transaction():
     transaction = validate_transaction(request.get_data())
     tx_type = transaction.tx_type
 
+    if tx_type == TransactionType.DEPLOY:
+        raise StarknetDevnetException(
+            code=StarknetErrorCode.DEPRECATED_TRANSACTION,
+            message="Deploy transaction is no longer supported.",
+        )
+
+    if _get_dry_run(request.args):
+        if tx_type == TransactionType.DECLARE:
+            raise StarknetDevnetException(
+                code=StarkErrorCode.MALFORMED_REQUEST,
+                message="Dry run is not supported for declare transactions.",
+                status_code=400,
+            )
+
+        dry_run_tx = await state.starknet_wrapper.dry_run(transaction)
+        return jsonify(
+            {
+                "receipt": dry_run_tx.get_receipt().dump(),
+                "trace": dry_run_tx.get_trace().dump(),
+            }
+        )
+
     response_dict = {
         "code": StarkErrorCode.TRANSACTION_RECEIVED.name,
     }
 
     if tx_type == TransactionType.DECLARE:
         contract_class_hash, transaction_hash = await state.starknet_wrapper.declare(
-            transaction
+            transaction, use_next_transaction_hash=True
         )
         response_dict["class_hash"] = hex(contract_class_hash)
 
@@ -38,18 +78,14 @@ async def add_transaction():
         (
             contract_address,
             transaction_hash,
-        ) = await state.starknet_wrapper.deploy_account(transaction)
-        response_dict["address"] = fixed_length_hex(contract_address)
-
-    elif tx_type == TransactionType.DEPLOY:
-        raise StarknetDevnetException(
-            code=StarknetErrorCode.DEPRECATED_TRANSACTION,
-            message="Deploy transaction is no longer supported.",
+        ) = await state.starknet_wrapper.deploy_account(
+            transaction, use_next_transaction_hash=True
         )
+        response_dict["address"] = fixed_length_hex(contract_address)
 
     elif tx_type == TransactionType.INVOKE_FUNCTION:
         (contract_address, transaction_hash) = await state.starknet_wrapper.invoke(
-            transaction
+            transaction, use_next_transaction_hash=True
         )
         response_dict["address"] = fixed_length_hex(contract_address)
 
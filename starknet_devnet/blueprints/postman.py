@@ -83,6 +83,21 @@ async def send_message_to_l2():
     return jsonify({"transaction_hash": rpc_felt(result)})
 
 
+@postman.route("/send_message_to_l1", methods=["POST"])
+async def send_message_to_l1():
+    """L2 to L1 message injection endpoint"""
+    request_json = request.json or {}
+
+    from_address = hex_converter(request_json, "l2_contract_address")
+    to_address = hex_converter(request_json, "l1_contract_address")
+    payload = hex_converter(request_json, "payload", to_int_array)
+
+    result = await state.starknet_wrapper.send_message_to_l1(
+        from_address, to_address, payload
+    )
+    return jsonify({"message_hash": result})
+
+
 @postman.route("/consume_message_from_l2", methods=["POST"])
 async def consume_message_from_l2():
     """L2 to L1 message mock endpoint"""
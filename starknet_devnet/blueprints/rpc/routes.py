@@ -29,10 +29,12 @@ from starknet_devnet.blueprints.rpc.misc import chain_id, get_events, get_nonce,
 from starknet_devnet.blueprints.rpc.schema import (
     ParamsValidationErrorWrapper,
     ResponseValidationErrorWrapper,
+    get_openrpc_document,
 )
 from starknet_devnet.blueprints.rpc.state import get_state_update
 from starknet_devnet.blueprints.rpc.storage import get_storage_at
 from starknet_devnet.blueprints.rpc.structures.types import (
+    MessageId,
     PredefinedRpcErrorCode,
     RpcError,
     map_gateway_to_rpc_error_dict,
@@ -78,6 +80,11 @@ methods = {
     "simulateTransactions": simulate_transaction,
 }
 
+# methods served under a name different from the one in the spec
+spec_aliases = {
+    "simulateTransactions": "simulateTransaction",
+}
+
 rpc = Blueprint("rpc", __name__, url_prefix="/rpc")
 
 
@@ -89,7 +96,8 @@ async def base_route():
 
     message_id = None
     try:
-        method, params, message_id = parse_body(request.json)
+        message_id = parse_message_id(request.json)
+        method, params = parse_body(request.json)
         result = await (
             method(*params) if isinstance(params, list) else method(**params)
         )
@@ -98,7 +106,12 @@ async def base_route():
     except TypeError as type_error:
         return rpc_error(message_id=message_id, code=22, message=str(type_error))
     except RpcError as error:
-        return rpc_error(message_id=message_id, code=error.code, message=error.message)
+        return rpc_error(
+            message_id=message_id,
+            code=error.code,
+            message=error.message,
+            data=error.data,
+        )
     except ParamsValidationErrorWrapper as error:
         return rpc_error(
             message_id=message_id,
@@ -118,15 +131,52 @@ async def base_route():
     return rpc_response(message_id=message_id, content=result)
 
 
-def parse_body(body: dict) -> Tuple[Callable, Union[List, dict], int]:
+@rpc.route("/methods", methods=["GET"])
+def supported_methods():
+    """
+    List the names of supported RPC methods
+    """
+    return {"methods": [f"starknet_{method_name}" for method_name in methods]}
+
+
+@rpc.route("/spec", methods=["GET"])
+def openrpc_spec():
+    """
+    Return the OpenRPC specification of supported RPC methods
+    """
+    method_names = {}
+    for method_name in methods:
+        spec_name = spec_aliases.get(method_name, method_name)
+        method_names[f"starknet_{method_name}"] = f"starknet_{spec_name}"
+
+    return get_openrpc_document(method_names)
+
+
+def parse_message_id(body: dict) -> MessageId:
+    """
+    Parse message id; it is echoed back as received, so it must be of a JSON-RPC type
+    """
+    message_id = body.get("id") if isinstance(body, dict) else None
+
+    if isinstance(message_id, bool) or not isinstance(
+        message_id, (int, float, str, type(None))
+    ):
+        raise RpcError(
+            code=PredefinedRpcErrorCode.INVALID_REQUEST.value,
+            message="Invalid request: id must be a string, a number or null",
+        )
+
+    return message_id
+
+
+def parse_body(body: dict) -> Tuple[Callable, Union[List, dict]]:
     """
-    Parse rpc call body to function name, params and message id
+    Parse rpc call body to function name and params
     """
     try:
         method_name = body["method"].replace("starknet_", "")
         params: Union[List, dict] = body.get("params") or {}
-        message_id = body["id"]
-    except RuntimeError as error:
+    except (KeyError, TypeError, AttributeError) as error:
         raise RpcError(
             code=PredefinedRpcErrorCode.INVALID_REQUEST.value, message="Invalid request"
         ) from error
@@ -143,4 +193,4 @@ def parse_body(body: dict) -> Tuple[Callable, Union[List, dict], int]:
             message="""Invalid "params" type. Value of "params" must be a dict or list""",
         )
 
-    return methods[method_name], params, message_id
+    return methods[method_name], params
@@ -17,6 +17,7 @@ from starknet_devnet.blueprints.rpc.rpc_spec_write import RPC_SPECIFICATION_WRIT
 from starknet_devnet.blueprints.rpc.rpc_trace_spec import RPC_SPECIFICATION_TRACE
 from starknet_devnet.state import state
 
+MAIN_SPEC_FILE_NAME = "./api/starknet_api_openrpc.json"
 
 # Cache the function result so schemas are not reloaded from disk on every call
 @lru_cache
@@ -39,6 +40,45 @@ def _extract_methods(specs_json: Dict[str, Any]) -> Dict[str, Any]:
     return {method["name"]: method for method in specs_json["methods"]}
 
 
+def get_openrpc_document(method_names: Dict[str, str]) -> Dict[str, Any]:
+    """
+    Return an OpenRPC document describing the provided methods.
+    `method_names` maps the names under which methods are served to their names in the spec.
+    """
+    specs_json = json.loads(RPC_SPECIFICATION)
+    write_specs_json = json.loads(RPC_SPECIFICATION_WRITE)
+    trace_specs_json = json.loads(RPC_SPECIFICATION_TRACE)
+    spec_methods = {
+        **_extract_methods(specs_json),
+        **_extract_methods(write_specs_json),
+        **_extract_methods(trace_specs_json),
+    }
+
+    served_methods = [
+        {**spec_methods[spec_name], "name": served_name}
+        for served_name, spec_name in method_names.items()
+    ]
+    # write and trace specs reference the main spec, whose components are included
+    served_methods_json = json.dumps(served_methods)
+    served_methods_json = served_methods_json.replace(f"{MAIN_SPEC_FILE_NAME}#", "#")
+
+    # on name collision, components of the main spec take precedence
+    components = {
+        category: {
+            **write_specs_json["components"][category],
+            **trace_specs_json["components"][category],
+            **main_components,
+        }
+        for category, main_components in specs_json["components"].items()
+    }
+
+    return {
+        **specs_json,
+        "methods": json.loads(served_methods_json),
+        "components": components,
+    }
+
+
 @lru_cache
 def _response_schema_for_method(name: str) -> Dict[str, Any]:
     """
@@ -41,6 +41,8 @@ class RpcDeployAccountTransactionResult(TypedDict):
 
     transaction_hash: TxnHash
     contract_address: Felt
+    # not part of the specification, but spares a call to getClassHashAt
+    class_hash: Felt
 
 
 class MessageToL1(TypedDict):
@@ -61,6 +61,8 @@ TxnHash = Felt
 Address = Felt
 NumAsHex = str
 
+MessageId = Union[int, float, str, None]
+
 RpcTxnType = Literal["DECLARE", "DEPLOY", "INVOKE", "L1_HANDLER", "DEPLOY_ACCOUNT"]
 
 
@@ -88,16 +90,17 @@ class RpcError(Exception):
     Error message returned by rpc
     """
 
-    def __init__(self, code, message):
+    def __init__(self, code, message, data=None):
         super().__init__(message)
         self.code = code
         self.message = message
+        self.data = data
 
     @staticmethod
-    def from_spec_name(name: str):
-        """Create an instance of this class, given only its name"""
+    def from_spec_name(name: str, data=None):
+        """Create an instance of this class, given its name and optional data"""
         error_dict = RPC_ERRORS[name]
-        return RpcError(**error_dict)
+        return RpcError(**error_dict, data=data)
 
 
 class PredefinedRpcErrorCode(Enum):
@@ -108,7 +108,8 @@ async def add_invoke_transaction(invoke_transaction: RpcBroadcastedInvokeTxn) ->
     Submit a new transaction to be added to the chain
     """
     _, transaction_hash = await state.starknet_wrapper.invoke(
-        external_tx=make_invoke_function(invoke_transaction)
+        external_tx=make_invoke_function(invoke_transaction),
+        use_next_transaction_hash=True,
     )
     return RpcInvokeTransactionResult(
         transaction_hash=rpc_felt(transaction_hash),
@@ -127,7 +128,8 @@ async def add_declare_transaction(
         raise RpcError.from_spec_name("INVALID_CONTRACT_CLASS")
 
     class_hash, transaction_hash = await state.starknet_wrapper.declare(
-        external_tx=make_declare(declare_transaction)
+        external_tx=make_declare(declare_transaction),
+        use_next_transaction_hash=True,
     )
     status_response = await state.starknet_wrapper.transactions.get_transaction_status(
         hex(transaction_hash)
@@ -156,8 +158,9 @@ async def add_deploy_account_transaction(
     """
     Submit a new deploy account transaction
     """
+    deploy_account_tx = make_deploy_account(deploy_account_transaction)
     contract_address, transaction_hash = await state.starknet_wrapper.deploy_account(
-        external_tx=make_deploy_account(deploy_account_transaction)
+        external_tx=deploy_account_tx, use_next_transaction_hash=True
     )
 
     status_response = await state.starknet_wrapper.transactions.get_transaction_status(
@@ -176,6 +179,7 @@ async def add_deploy_account_transaction(
     return RpcDeployAccountTransactionResult(
         transaction_hash=rpc_felt(transaction_hash),
         contract_address=rpc_felt(contract_address),
+        class_hash=rpc_felt(deploy_account_tx.class_hash),
     )
 
 
@@ -195,6 +199,13 @@ def make_transaction(txn: RpcBroadcastedTxn) -> AccountTransaction:
     raise NotImplementedError(f"Unexpected type {txn_type}.")
 
 
+def _contract_error(revert_error: str) -> RpcError:
+    """Contract error carrying the reason of the failure"""
+    return RpcError.from_spec_name(
+        "CONTRACT_ERROR", data={"revert_error": revert_error}
+    )
+
+
 async def _calculate_traces_and_fees(
     transactions: List[AccountTransaction], block_id: BlockId, skip_validate: bool
 ):
@@ -210,9 +221,9 @@ async def _calculate_traces_and_fees(
         )
     except StarkException as ex:
         if "Entry point" in ex.message and "not found" in ex.message:
-            raise RpcError.from_spec_name("CONTRACT_ERROR") from ex
+            raise _contract_error(ex.message) from ex
         if "While handling calldata" in ex.message:
-            raise RpcError.from_spec_name("CONTRACT_ERROR") from ex
+            raise _contract_error(ex.message) from ex
         if "is not deployed" in ex.message:
             raise RpcError.from_spec_name("CONTRACT_NOT_FOUND") from ex
         raise RpcError(code=-1, message=ex.message) from ex
@@ -223,9 +234,15 @@ async def estimate_fee(request: List[RpcBroadcastedTxn], block_id: BlockId) -> l
     """Estimate the fee for the given Starknet transaction"""
 
     gateway_transactions = list(map(make_transaction, request))
-    _, fee_responses = await _calculate_traces_and_fees(
+    traces, fee_responses = await _calculate_traces_and_fees(
         gateway_transactions, block_id, skip_validate=False
     )
+
+    # unlike simulation, estimation fails if a transaction would be reverted
+    for trace in traces:
+        if trace.revert_error:
+            raise _contract_error(trace.revert_error)
+
     return rpc_fee_estimate(fee_responses)
 
 
@@ -10,6 +10,7 @@ from starkware.starknet.services.api.feeder_gateway.response_objects import (
 from starknet_devnet.blueprints.rpc.structures.types import (
     BlockId,
     Felt,
+    MessageId,
     PredefinedRpcErrorCode,
     RpcError,
 )
@@ -114,19 +115,23 @@ def rpc_root(root: Union[int, str]) -> Felt:
     return rpc_felt(root)
 
 
-def rpc_response(message_id: int, content: dict) -> dict:
+def rpc_response(message_id: MessageId, content: dict) -> dict:
     """
     Wrap response content in rpc format
     """
     return {"jsonrpc": "2.0", "id": message_id, "result": content}
 
 
-def rpc_error(message_id: int, code: int, message: str) -> dict:
+def rpc_error(message_id: MessageId, code: int, message: str, data=None) -> dict:
     """
     Wrap error in rpc format
     """
+    error = {"code": code, "message": message}
+    if data is not None:
+        error["data"] = data
+
     return {
         "jsonrpc": "2.0",
         "id": message_id,
-        "error": {"code": code, "message": message},
+        "error": error,
     }
@@ -3,9 +3,15 @@ Shared functions between blueprints
 """
 
 from marshmallow import ValidationError
+from starkware.starknet.services.api.feeder_gateway.response_objects import (
+    LATEST_BLOCK_ID,
+    PENDING_BLOCK_ID,
+)
 from starkware.starknet.services.api.gateway.transaction import Transaction
 from starkware.starkware_utils.error_handling import StarkErrorCode
+from werkzeug.datastructures import MultiDict
 
+from starknet_devnet.blueprints.rpc.structures.types import BlockId
 from starknet_devnet.constants import CAIRO_LANG_VERSION
 from starknet_devnet.util import StarknetDevnetException
 
@@ -20,3 +26,22 @@ Be sure to use the correct compilation (json) artifact. Devnet-compatible cairo-
         raise StarknetDevnetException(
             code=StarkErrorCode.MALFORMED_REQUEST, message=msg, status_code=400
         ) from err
+
+
+def get_block_id(args: MultiDict) -> BlockId:
+    """Extracts the block id from `blockNumber` or `blockHash` of query `args`"""
+    block_number = args.get("blockNumber")
+    block_hash = args.get("blockHash")
+
+    if block_number is None and block_hash is None:
+        return "latest"
+
+    if block_number is None:
+        # there is some hash
+        return {"block_hash": block_hash}
+
+    if block_number in [PENDING_BLOCK_ID, LATEST_BLOCK_ID]:
+        return block_number
+
+    # there is some number and it should be an integer
+    return {"block_number": block_number}
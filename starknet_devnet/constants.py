@@ -4,6 +4,7 @@ from importlib.metadata import version
 
 CAIRO_LANG_VERSION = version("cairo-lang")
 TIMEOUT_FOR_WEB3_REQUESTS = 120  # seconds
+FORK_ORIGIN_HEALTH_CHECK_TIMEOUT = 3  # seconds
 L1_MESSAGE_CANCELLATION_DELAY = (
     0  # Min amount of time in seconds for a message to be able to be cancelled
 )
@@ -26,6 +27,14 @@ DUMMY_PENDING_BLOCK_HASH = 0
 
 DEFAULT_TIMEOUT = 60  # seconds
 
+DEFAULT_MAX_BATCH_SIZE = 1000
+
+MAX_SHORT_STRING_LENGTH = 31
+
+DEFAULT_ACTION_HISTORY_SIZE = 100
+
+MAX_CREATED_BLOCKS_COUNT = 1000
+
 OLD_SUPPORTED_VERSIONS = [0]
 
 # account used by Starknet CLI; calculated using
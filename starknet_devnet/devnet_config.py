@@ -4,18 +4,24 @@ import argparse
 import asyncio
 import json
 import os
+import random
 import subprocess
 import sys
 from enum import Enum, auto
-from typing import List
+from typing import List, Optional, Tuple
 
 from aiohttp.client_exceptions import ClientConnectorError, InvalidURL
 from marshmallow.exceptions import ValidationError
 from services.external_api.client import BadRequest, RetryConfig
+from starkware.cairo.lang.cairo_constants import DEFAULT_PRIME
 from starkware.starknet.core.os.contract_class.deprecated_class_hash import (
     compute_deprecated_class_hash,
 )
-from starkware.starknet.definitions.general_config import StarknetChainId
+from starkware.starknet.definitions.constants import L2_ADDRESS_UPPER_BOUND
+from starkware.starknet.definitions.general_config import (
+    DEFAULT_SEQUENCER_ADDRESS,
+    StarknetChainId,
+)
 from starkware.starknet.services.api.contract_class.contract_class import (
     DeprecatedCompiledClass,
 )
@@ -23,22 +29,31 @@ from starkware.starknet.services.api.feeder_gateway.feeder_gateway_client import
     FeederGatewayClient,
 )
 
-from starknet_devnet.util import suppress_feeder_gateway_client_logger, warn
+from starknet_devnet.util import (
+    str_to_felt,
+    suppress_feeder_gateway_client_logger,
+    warn,
+)
 
 from . import __version__
 from .constants import (
     DEFAULT_ACCOUNTS,
+    DEFAULT_ACTION_HISTORY_SIZE,
     DEFAULT_GAS_PRICE,
     DEFAULT_HOST,
     DEFAULT_INITIAL_BALANCE,
+    DEFAULT_MAX_BATCH_SIZE,
     DEFAULT_PORT,
     DEFAULT_TIMEOUT,
+    MAX_SHORT_STRING_LENGTH,
 )
 from .contract_class_wrapper import (
     DEFAULT_ACCOUNT_HASH,
     DEFAULT_ACCOUNT_PATH,
     CompiledClassWrapper,
 )
+from .fee_token import FeeToken
+from .general_config import ChainId, to_chain_id
 
 NETWORK_TO_URL = {
     "alpha-goerli": "https://alpha4.starknet.io",
@@ -62,32 +77,148 @@ def _fork_network(network_id: str):
     return NETWORK_TO_URL.get(network_id, network_id)
 
 
+RELATIVE_FORK_BLOCK_PREFIX = "latest-"
+
+
 def _fork_block(specifier: str):
-    """Parse block specifier; allows int and 'latest'"""
+    """Parse block specifier; allows int, 'latest' and 'latest-N'"""
     if specifier == "latest":
         return specifier
 
     try:
+        if specifier.startswith(RELATIVE_FORK_BLOCK_PREFIX):
+            offset = int(specifier[len(RELATIVE_FORK_BLOCK_PREFIX) :])
+            assert offset >= 0
+            return specifier
+
         parsed = int(specifier)
         assert parsed > 0
     except (AssertionError, ValueError):
         sys.exit(
-            f"The value of --fork-block must be a non-negative integer or 'latest', got: {specifier}"
+            "The value of --fork-block must be a non-negative integer, 'latest' or 'latest-N', "
+            f"got: {specifier}"
         )
 
     return parsed
 
 
-def _chain_id(chain_id: str):
-    """Parse chain id.'"""
+def _resolve_fork_block(block_id, latest_block_number: int) -> int:
+    """Resolve the parsed --fork-block value to a block number of the origin"""
+    if block_id == "latest":
+        return latest_block_number
+
+    if isinstance(block_id, str):  # relative to latest
+        offset = int(block_id[len(RELATIVE_FORK_BLOCK_PREFIX) :])
+        if offset > latest_block_number:
+            sys.exit(
+                f"Error: Cannot fork {block_id}; the latest block of the origin is {latest_block_number}"
+            )
+        return latest_block_number - offset
+
+    if block_id > latest_block_number:
+        sys.exit(
+            f"Error: The value of --fork-block ({block_id}) is greater than the latest block "
+            f"of the origin ({latest_block_number}); consider using 'latest'"
+        )
+    return block_id
+
+
+def _fork_block_hash(block_hash: str) -> int:
+    """Parse the hash of the block where the origin is forked"""
+    return _parse_hex_arg(block_hash, "--fork-block-hash", DEFAULT_PRIME)
+
+
+def _chain_id(chain_id: str) -> ChainId:
+    """
+    Parse chain id: the name of a known chain id, a 0x-prefixed hex string
+    of a field element or a short string (e.g. SN_CUSTOM).
+    """
+    if chain_id in StarknetChainId.__members__:
+        return StarknetChainId[chain_id]
+
+    value = None
+    if chain_id.startswith("0x"):
+        try:
+            value = int(chain_id, 16)
+        except ValueError:
+            pass
+        if value is not None and value < DEFAULT_PRIME:
+            return to_chain_id(value, name=hex(value))
+    elif _is_short_string(chain_id):
+        return to_chain_id(str_to_felt(chain_id), name=chain_id)
+
+    sys.exit(
+        f"Error: The value of --chain-id must be in {{{CHAIN_IDS}}}, "
+        "a 0x-prefixed hex string smaller than the field prime "
+        f"or a string of at most {MAX_SHORT_STRING_LENGTH} ASCII characters, "
+        f"got: {chain_id}"
+    )
+
+
+def _parse_hex_arg(
+    value: str, flag: str, upper_bound: int, lower_exclusive: bool = False
+) -> int:
+    """
+    Parse `value` of `flag` as a 0x-prefixed hex string smaller than `upper_bound`
+    and at least 0 (or greater than 0 if `lower_exclusive`). Exit if invalid.
+    """
     try:
-        chain_id = StarknetChainId[chain_id]
-    except KeyError:
+        parsed = int(value, 16)
+    except ValueError:
+        parsed = -1
+
+    lower_bound = 1 if lower_exclusive else 0
+    if not value.startswith("0x") or not lower_bound <= parsed < upper_bound:
+        upper_bound_name = (
+            "the field prime" if upper_bound == DEFAULT_PRIME else hex(upper_bound)
+        )
+        lower_bound_name = "greater than 0 and " if lower_exclusive else ""
         sys.exit(
-            f"Error: The value of --chain-id must be in {{{CHAIN_IDS}}}, got: {chain_id}"
+            f"Error: The value of {flag} must be a 0x-prefixed hex string "
+            f"{lower_bound_name}smaller than {upper_bound_name}, got: {value}"
         )
 
-    return chain_id
+    return parsed
+
+
+def _genesis_parent_hash(block_hash: str) -> int:
+    """Parse the parent hash of the genesis block"""
+    return _parse_hex_arg(block_hash, "--genesis-parent-hash", DEFAULT_PRIME)
+
+
+def _parse_address_arg(address: str, flag: str) -> int:
+    """Parse `address` of `flag` as a contract address. Exit if invalid."""
+    return _parse_hex_arg(address, flag, L2_ADDRESS_UPPER_BOUND, lower_exclusive=True)
+
+
+def _predeployed_account_address(address: str) -> int:
+    """Parse the address of a predeployed account"""
+    return _parse_address_arg(address, "--predeployed-account-address")
+
+
+def _is_short_string(text: str) -> bool:
+    """Whether `text` is non-empty and can be stored in a single felt"""
+    return text.isascii() and 0 < len(text) <= MAX_SHORT_STRING_LENGTH
+
+
+def _predeployed_erc20(token: str) -> Tuple[str, str, int]:
+    """Parse the name, symbol and address of an additional ERC20 token"""
+    parts = token.split(":")
+    name, symbol, address = parts if len(parts) == 3 else ("", "", "")
+    if not all(_is_short_string(text) for text in (name, symbol)):
+        sys.exit(
+            "Error: The value of --predeploy-erc20 must be of the form "
+            "NAME:SYMBOL:ADDRESS, where NAME and SYMBOL consist of at most "
+            f"{MAX_SHORT_STRING_LENGTH} ASCII characters and ADDRESS is a 0x-prefixed "
+            f"hex string of a valid contract address, got: {token}"
+        )
+
+    return name, symbol, _parse_address_arg(address, "ADDRESS of --predeploy-erc20")
+
+
+def _sequencer_address(address: str) -> int:
+    """Parse the address of the sequencer"""
+    return _parse_address_arg(address, "--sequencer-address")
 
 
 class DumpOn(Enum):
@@ -95,21 +226,55 @@ class DumpOn(Enum):
 
     EXIT = auto()
     TRANSACTION = auto()
+    BLOCKS = auto()
 
 
-DUMP_ON_OPTIONS = [e.name.lower() for e in DumpOn]
-DUMP_ON_OPTIONS_STRINGIFIED = ", ".join(DUMP_ON_OPTIONS)
+DUMP_ON_OPTIONS = [e.name.lower() for e in DumpOn if e != DumpOn.BLOCKS]
+DUMP_ON_BLOCKS_PREFIX = "blocks:"
+DUMP_ON_OPTIONS_STRINGIFIED = ", ".join(
+    DUMP_ON_OPTIONS + [f"{DUMP_ON_BLOCKS_PREFIX}<N>"]
+)
 
 
 def _parse_dump_on(option: str):
-    """Parse dumping frequency option."""
+    """
+    Parse dumping frequency option.
+    Returns the frequency and, if dumping on blocks, the number of blocks.
+    """
     if option in DUMP_ON_OPTIONS:
-        return DumpOn[option.upper()]
+        return DumpOn[option.upper()], None
+
+    if option.startswith(DUMP_ON_BLOCKS_PREFIX):
+        block_count = option[len(DUMP_ON_BLOCKS_PREFIX) :]
+        if block_count.isdigit() and int(block_count) > 0:
+            return DumpOn.BLOCKS, int(block_count)
+
     sys.exit(
         f"Error: Invalid --dump-on option: {option}. Valid options: {DUMP_ON_OPTIONS_STRINGIFIED}"
     )
 
 
+class LogFormat(Enum):
+    """Enumerate possible formats of server logs."""
+
+    PLAIN = auto()
+    JSON = auto()
+
+
+LOG_FORMAT_OPTIONS = [e.name.lower() for e in LogFormat]
+
+
+def _parse_log_format(option: str):
+    """Parse the format of server logs."""
+    if option in LOG_FORMAT_OPTIONS:
+        return LogFormat[option.upper()]
+
+    sys.exit(
+        f"Error: Invalid --log-format option: {option}. "
+        f"Valid options: {', '.join(LOG_FORMAT_OPTIONS)}"
+    )
+
+
 EXPECTED_ACCOUNT_METHODS = ["__execute__", "__validate__", "__validate_declare__"]
 
 
@@ -147,8 +312,13 @@ def _parse_account_class(class_path: str) -> CompiledClassWrapper:
     return CompiledClassWrapper(contract_class, class_hash)
 
 
-def _get_feeder_gateway_client(url: str, block_id: str, n_retries: int = 1):
-    """Construct a feeder gateway client at url and block"""
+def _get_feeder_gateway_client(
+    url: str, block_id: str, n_retries: int = 1, block_hash: Optional[int] = None
+):
+    """
+    Construct a feeder gateway client at url and block.
+    If `block_hash` is provided, it takes precedence over `block_id`.
+    """
 
     feeder_gateway_client = FeederGatewayClient(
         url=url,
@@ -157,8 +327,9 @@ def _get_feeder_gateway_client(url: str, block_id: str, n_retries: int = 1):
 
     try:
         with suppress_feeder_gateway_client_logger:
-            block = asyncio.run(feeder_gateway_client.get_block(block_number=block_id))
-            block_number = block.block_number
+            latest_block = asyncio.run(
+                feeder_gateway_client.get_block(block_number="latest")
+            )
     except InvalidURL:
         sys.exit(
             f"Error: Invalid fork-network (must be a URL or one of {{{NETWORK_NAMES}}}). Received: {url}"
@@ -173,6 +344,20 @@ def _get_feeder_gateway_client(url: str, block_id: str, n_retries: int = 1):
     except ClientConnectorError as error:
         sys.exit(f"Error: {error}")
 
+    if block_hash is not None:
+        try:
+            with suppress_feeder_gateway_client_logger:
+                fork_block = asyncio.run(
+                    feeder_gateway_client.get_block(block_hash=hex(block_hash))
+                )
+        except BadRequest:
+            sys.exit(
+                f"Error: Block with the --fork-block-hash {hex(block_hash)} "
+                "not found on the origin"
+            )
+        return feeder_gateway_client, fork_block.block_number
+
+    block_number = _resolve_fork_block(block_id, latest_block.block_number)
     return feeder_gateway_client, block_number
 
 
@@ -299,6 +484,13 @@ def parse_args(raw_args: List[str]):
         action="store_true",
         help="Hide server access logging",
     )
+    parser.add_argument(
+        "--log-format",
+        type=_parse_log_format,
+        default=LogFormat.PLAIN,
+        help="Specify the format of server logs; "
+        f"one of: {', '.join(LOG_FORMAT_OPTIONS)}; defaults to plain",
+    )
     parser.add_argument(
         "--host",
         help=f"Specify the address to listen at; defaults to {DEFAULT_HOST} "
@@ -332,6 +524,18 @@ def parse_args(raw_args: List[str]):
         action="store_true",
         help="Block generation on demand via an endpoint.",
     )
+    parser.add_argument(
+        "--instant-l1-acceptance",
+        action="store_true",
+        help="Mark blocks and their transactions as accepted on L1"
+        " as soon as the blocks are created.",
+    )
+    parser.add_argument(
+        "--state-archive-limit",
+        action=PositiveAction,
+        help="Specify the number of most recent block states kept for querying; "
+        "states of older blocks are discarded; if omitted, all states are kept",
+    )
     parser.add_argument(
         "--accounts",
         action=NonNegativeAction,
@@ -346,11 +550,47 @@ def parse_args(raw_args: List[str]):
         f"defaults to {DEFAULT_INITIAL_BALANCE:g}",
         default=DEFAULT_INITIAL_BALANCE,
     )
+    parser.add_argument(
+        "--account-initial-nonce",
+        action=NonNegativeAction,
+        help="Specify the nonce of accounts to be predeployed; defaults to 0",
+        default=0,
+    )
+    parser.add_argument(
+        "--initial-total-supply",
+        action=NonNegativeAction,
+        help="Specify the total supply of the fee token excluding the funds of "
+        "predeployed accounts; if omitted, the total supply is not initialized; "
+        "not applicable when forking",
+    )
+    parser.add_argument(
+        "--auto-fund-deploy-account",
+        action=PositiveAction,
+        help="Specify the amount minted to the address of a deploy account transaction "
+        "if the address has no funds; if omitted, no funds are minted",
+    )
     parser.add_argument(
         "--seed",
         type=int,
         help="Specify the seed for randomness of accounts to be predeployed",
     )
+    parser.add_argument(
+        "--predeployed-account-address",
+        type=_predeployed_account_address,
+        action="append",
+        dest="predeployed_account_addresses",
+        help="Specify the address of a predeployed account; can be repeated, "
+        "once for each of the --accounts; keys are still derived from the seed",
+    )
+    parser.add_argument(
+        "--predeploy-erc20",
+        type=_predeployed_erc20,
+        action="append",
+        dest="predeployed_erc20s",
+        metavar="NAME:SYMBOL:ADDRESS",
+        help="Specify an ERC20 token to be predeployed in addition to the fee token; "
+        "can be repeated; the token is mintable like the fee token",
+    )
     parser.add_argument(
         "--hide-predeployed-contracts",
         "--hide-predeployed-accounts",  # for backwards compatibility
@@ -362,6 +602,24 @@ def parse_args(raw_args: List[str]):
         action=NonNegativeAction,
         help="Specify the start time of the genesis block in Unix time seconds",
     )
+    parser.add_argument(
+        "--block-time-increment",
+        action=PositiveAction,
+        help="Specify the number of seconds by which the timestamp of each block "
+        "is increased, regardless of wall clock; by default, wall clock is used",
+    )
+    parser.add_argument(
+        "--genesis-parent-hash",
+        type=_genesis_parent_hash,
+        help="Specify the parent hash of the genesis block as a hex string; "
+        "defaults to 0x0; not applicable when forking",
+    )
+    parser.add_argument(
+        "--starting-block-number",
+        action=NonNegativeAction,
+        help="Specify the number of the genesis block; defaults to 0; "
+        "not applicable when forking",
+    )
     parser.add_argument(
         "--gas-price",
         "-g",
@@ -374,6 +632,18 @@ def parse_args(raw_args: List[str]):
         action="store_true",
         help="Allow transactions with max fee equal to zero",
     )
+    parser.add_argument(
+        "--lenient-declare",
+        action="store_true",
+        help="Instead of reverting the declaration of an already declared Cairo 1 "
+        "class, respond with the hash of the transaction which declared it",
+    )
+    parser.add_argument(
+        "--store-rejected",
+        action="store_true",
+        help="Store transactions which fail validation (e.g. due to an invalid nonce "
+        "or insufficient balance) so that they can be queried by hash",
+    )
     parser.add_argument(
         "--timeout",
         "-t",
@@ -381,6 +651,26 @@ def parse_args(raw_args: List[str]):
         default=DEFAULT_TIMEOUT,
         help=f"Specify the server timeout in seconds; defaults to {DEFAULT_TIMEOUT}",
     )
+    parser.add_argument(
+        "--request-body-size-limit",
+        action=PositiveAction,
+        help="Specify the maximum size of a request body in bytes; "
+        "by default, the size is not limited",
+    )
+    parser.add_argument(
+        "--max-batch-size",
+        action=PositiveAction,
+        default=DEFAULT_MAX_BATCH_SIZE,
+        help="Specify the maximum number of transactions in a single fee estimation "
+        f"or simulation request; defaults to {DEFAULT_MAX_BATCH_SIZE}",
+    )
+    parser.add_argument(
+        "--action-history-size",
+        action=NonNegativeAction,
+        default=DEFAULT_ACTION_HISTORY_SIZE,
+        help="Specify the number of the most recent Devnet-specific actions "
+        f"to be remembered; defaults to {DEFAULT_ACTION_HISTORY_SIZE}",
+    )
     parser.add_argument(
         "--account-class",
         help="Specify the account implementation to be used for predeploying; "
@@ -398,7 +688,14 @@ def parse_args(raw_args: List[str]):
     parser.add_argument(
         "--fork-block",
         type=_fork_block,
-        help="Specify the block number where the --fork-network is forked; defaults to latest",
+        help="Specify the block number where the --fork-network is forked; "
+        "can be relative to the latest block (e.g. latest-10); defaults to latest",
+    )
+    parser.add_argument(
+        "--fork-block-hash",
+        type=_fork_block_hash,
+        help="Specify the hash of the block where the --fork-network is forked; "
+        "cannot be used with --fork-block",
     )
     parser.add_argument(
         "--fork-retries",
@@ -411,7 +708,16 @@ def parse_args(raw_args: List[str]):
         "--chain-id",
         type=_chain_id,
         default=DEFAULT_CHAIN_ID,
-        help=f"Specify the chain id as one of: {{{CHAIN_IDS}}}; defaults to {DEFAULT_CHAIN_ID.name} ({hex(DEFAULT_CHAIN_ID.value)})",
+        help=f"Specify the chain id as one of: {{{CHAIN_IDS}}}, "
+        "as a 0x-prefixed hex string or as a short string (e.g. SN_CUSTOM); "
+        f"defaults to {DEFAULT_CHAIN_ID.name} ({hex(DEFAULT_CHAIN_ID.value)})",
+    )
+    parser.add_argument(
+        "--sequencer-address",
+        type=_sequencer_address,
+        default=DEFAULT_SEQUENCER_ADDRESS,
+        help="Specify the address of the sequencer, which collects transaction fees; "
+        "defaults to the sequencer address of cairo-lang",
     )
     parser.add_argument(
         "--disable-rpc-request-validation",
@@ -448,13 +754,62 @@ def parse_args(raw_args: List[str]):
     if parsed_args.dump_on and not parsed_args.dump_path:
         sys.exit("Error: --dump-path required if --dump-on present")
 
+    dump_on_option = parsed_args.dump_on or (None, None)
+    parsed_args.dump_on, parsed_args.dump_block_count = dump_on_option
+
     if parsed_args.fork_block and not parsed_args.fork_network:
         sys.exit("Error: --fork-network required if --fork-block present")
 
+    if parsed_args.fork_block_hash is not None:
+        if not parsed_args.fork_network:
+            sys.exit("Error: --fork-network required if --fork-block-hash present")
+        if parsed_args.fork_block:
+            sys.exit(
+                "Error: Only one of {--fork-block,--fork-block-hash} can be provided"
+            )
+
+    if parsed_args.genesis_parent_hash is not None and parsed_args.fork_network:
+        sys.exit("Error: --genesis-parent-hash cannot be used with --fork-network")
+
+    if parsed_args.starting_block_number is not None and parsed_args.fork_network:
+        sys.exit("Error: --starting-block-number cannot be used with --fork-network")
+
+    addresses = parsed_args.predeployed_account_addresses
+    if addresses is not None:
+        if len(addresses) != parsed_args.accounts:
+            sys.exit(
+                f"Error: {len(addresses)} --predeployed-account-address provided, "
+                f"but --accounts is {parsed_args.accounts}"
+            )
+        if len(set(addresses)) != len(addresses):
+            sys.exit("Error: --predeployed-account-address values must be unique")
+
+    token_addresses = [
+        address for _, _, address in parsed_args.predeployed_erc20s or []
+    ]
+    if FeeToken.ADDRESS in token_addresses:
+        sys.exit("Error: --predeploy-erc20 cannot use the address of the fee token")
+    if len(set(token_addresses)) != len(token_addresses):
+        sys.exit("Error: --predeploy-erc20 addresses must be unique")
+
+    if parsed_args.initial_total_supply is not None:
+        if parsed_args.fork_network:
+            sys.exit("Error: --initial-total-supply cannot be used with --fork-network")
+
+        predeployed_funds = parsed_args.accounts * parsed_args.initial_balance
+        if parsed_args.initial_total_supply + predeployed_funds >= 2**256:
+            sys.exit(
+                "Error: --initial-total-supply together with the funds of predeployed "
+                "accounts must be less than 2**256"
+            )
+
     if parsed_args.fork_network:
         parsed_args.fork_block = parsed_args.fork_block or "latest"
         parsed_args.fork_network, parsed_args.fork_block = _get_feeder_gateway_client(
-            parsed_args.fork_network, parsed_args.fork_block, parsed_args.fork_retries
+            parsed_args.fork_network,
+            parsed_args.fork_block,
+            parsed_args.fork_retries,
+            block_hash=parsed_args.fork_block_hash,
         )
 
     if parsed_args.cairo_compiler_manifest and parsed_args.sierra_compiler_path:
@@ -475,17 +830,35 @@ class DevnetConfig:
         self.args = args or parse_args(["--accounts", "0"])
         self.accounts = self.args.accounts
         self.initial_balance = self.args.initial_balance
+        self.account_initial_nonce = self.args.account_initial_nonce
+        self.initial_total_supply = self.args.initial_total_supply
+        self.auto_fund_deploy_account = self.args.auto_fund_deploy_account
+        self.predeployed_account_addresses = self.args.predeployed_account_addresses
+        self.predeployed_erc20s = self.args.predeployed_erc20s or []
         self.seed = self.args.seed
+        if self.seed is None:
+            # chosen once, so that restarting regenerates the same accounts
+            self.seed = random.getrandbits(32)
         self.start_time = self.args.start_time
+        self.block_time_increment = self.args.block_time_increment
+        self.genesis_parent_hash = self.args.genesis_parent_hash or 0
+        self.starting_block_number = self.args.starting_block_number or 0
         self.gas_price = self.args.gas_price
         self.allow_max_fee_zero = self.args.allow_max_fee_zero
+        self.lenient_declare = self.args.lenient_declare
+        self.store_rejected = self.args.store_rejected
+        self.max_batch_size = self.args.max_batch_size
+        self.action_history_size = self.args.action_history_size
         self.lite_mode = self.args.lite_mode
         self.blocks_on_demand = self.args.blocks_on_demand
+        self.instant_l1_acceptance = self.args.instant_l1_acceptance
+        self.state_archive_limit = self.args.state_archive_limit
         self.account_class = self.args.account_class
         self.hide_predeployed_contracts = self.args.hide_predeployed_contracts
         self.fork_network = self.args.fork_network
         self.fork_block = self.args.fork_block
         self.chain_id = self.args.chain_id
+        self.sequencer_address = self.args.sequencer_address
         self.validate_rpc_requests = not self.args.disable_rpc_request_validation
         self.validate_rpc_responses = not self.args.disable_rpc_response_validation
         self.cairo_compiler_manifest = self.args.cairo_compiler_manifest
@@ -19,6 +19,12 @@ class Dumper:
         self.dump_on: DumpOn = None
         """When to dump."""
 
+        self.dump_block_count: int = None
+        """With `DumpOn.BLOCKS`, after how many blocks to dump."""
+
+        self.dumped_block_count = 0
+        """The number of blocks at the time of the last dump on blocks."""
+
     def __write_file(self, path):
         """Writes the dump to disk."""
         with open(path, "wb") as file:
@@ -31,3 +37,13 @@ class Dumper:
 
         print("Dumping Devnet to:", path)
         self.__write_file(path)
+
+    def dump_if_block_count_reached(self, block_count: int):
+        """Dump if `dump_block_count` blocks have been created since the last dump."""
+        if block_count < self.dumped_block_count:
+            # blocks were aborted or a state with fewer blocks was loaded
+            self.dumped_block_count = block_count
+
+        if block_count - self.dumped_block_count >= self.dump_block_count:
+            self.dump()
+            self.dumped_block_count = block_count
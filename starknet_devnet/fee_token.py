@@ -15,7 +15,8 @@ from starkware.starknet.services.api.contract_class.contract_class import (
 from starkware.starknet.services.api.gateway.transaction import InvokeFunction
 from starkware.starknet.testing.starknet import Starknet
 
-from starknet_devnet.account_util import get_execute_args
+from starknet_devnet.account_util import get_balance_key, get_execute_args
+from starknet_devnet.blueprints.rpc.structures.types import BlockId
 from starknet_devnet.chargeable_account import ChargeableAccount
 from starknet_devnet.constants import SUPPORTED_TX_VERSION
 from starknet_devnet.predeployed_contract_wrapper import PredeployedContractWrapper
@@ -23,7 +24,10 @@ from starknet_devnet.util import Uint256, logger, str_to_felt
 
 
 class FeeToken(PredeployedContractWrapper):
-    """Wrapper of token for charging fees."""
+    """
+    Wrapper of token for charging fees.
+    Also used for additional ERC20 tokens predeployed with a different address.
+    """
 
     CONTRACT_CLASS: CompiledClassBase = None  # loaded lazily
 
@@ -37,11 +41,18 @@ class FeeToken(PredeployedContractWrapper):
     SYMBOL = "ETH"
     NAME = "ether"
 
-    def __init__(self, starknet_wrapper):
+    def __init__(self, starknet_wrapper, name=NAME, symbol=SYMBOL, address=ADDRESS):
         self.starknet_wrapper = starknet_wrapper
-        self.address = self.ADDRESS
+        self.name = name
+        self.symbol = symbol
+        self.address = address
         self.class_hash = self.HASH
 
+    @property
+    def is_fee_token(self) -> bool:
+        """Whether this is the token used for charging fees"""
+        return self.address == FeeToken.ADDRESS
+
     @classmethod
     def get_contract_class(cls) -> CompiledClassBase:
         """Returns contract class via lazy loading."""
@@ -60,31 +71,65 @@ class FeeToken(PredeployedContractWrapper):
         starknet: Starknet = self.starknet_wrapper.starknet
         await starknet.state.state.set_storage_at(
             storage_domain=StorageDomain.ON_CHAIN,
-            contract_address=FeeToken.ADDRESS,
+            contract_address=self.address,
             key=get_selector_from_name("ERC20_name"),
-            value=str_to_felt(FeeToken.NAME),
+            value=str_to_felt(self.name),
         )
         await starknet.state.state.set_storage_at(
             storage_domain=StorageDomain.ON_CHAIN,
-            contract_address=FeeToken.ADDRESS,
+            contract_address=self.address,
             key=get_selector_from_name("ERC20_symbol"),
-            value=str_to_felt(FeeToken.SYMBOL),
+            value=str_to_felt(self.symbol),
         )
         await starknet.state.state.set_storage_at(
             storage_domain=StorageDomain.ON_CHAIN,
-            contract_address=FeeToken.ADDRESS,
+            contract_address=self.address,
             key=get_selector_from_name("ERC20_decimals"),
             value=18,
         )
         await starknet.state.state.set_storage_at(
             storage_domain=StorageDomain.ON_CHAIN,
-            contract_address=FeeToken.ADDRESS,
+            contract_address=self.address,
             key=get_selector_from_name("Ownable_owner"),
             value=ChargeableAccount.ADDRESS,
         )
 
-    async def get_balance(self, address: int) -> int:
-        """Return the balance of the contract under `address`."""
+        config = self.starknet_wrapper.config
+        if self.is_fee_token and config.initial_total_supply is not None:
+            # the funds of predeployed accounts are set directly, not minted
+            predeployed_funds = config.accounts * config.initial_balance
+            total_supply = Uint256.from_felt(
+                config.initial_total_supply + predeployed_funds
+            )
+            total_supply_key = get_selector_from_name("ERC20_total_supply")
+            await starknet.state.state.set_storage_at(
+                storage_domain=StorageDomain.ON_CHAIN,
+                contract_address=self.address,
+                key=total_supply_key,
+                value=total_supply.low,
+            )
+            await starknet.state.state.set_storage_at(
+                storage_domain=StorageDomain.ON_CHAIN,
+                contract_address=self.address,
+                key=total_supply_key + 1,
+                value=total_supply.high,
+            )
+
+    async def get_balance(self, address: int, block_id: BlockId = None) -> int:
+        """
+        Return the balance of the contract under `address`.
+        If `block_id` is provided, the balance is read from the state of that block.
+        """
+        if block_id is not None:
+            balance_key = get_balance_key(address)
+            low = await self.starknet_wrapper.get_storage_at(
+                self.address, balance_key, block_id
+            )
+            high = await self.starknet_wrapper.get_storage_at(
+                self.address, balance_key + 1, block_id
+            )
+            return Uint256(low=int(low, 16), high=int(high, 16)).to_felt()
+
         response = await self.contract.balanceOf(address).call()
 
         balance = Uint256(
@@ -112,7 +157,7 @@ class FeeToken(PredeployedContractWrapper):
         )
         chargeable_address = hex(ChargeableAccount.ADDRESS)
         signature, execute_calldata = get_execute_args(
-            calls=[(hex(FeeToken.ADDRESS), "mint", calldata)],
+            calls=[(hex(self.address), "mint", calldata)],
             account_address=chargeable_address,
             private_key=ChargeableAccount.PRIVATE_KEY,
             nonce=nonce,
@@ -159,8 +204,8 @@ class FeeToken(PredeployedContractWrapper):
 
     def print(self):
         print("")
-        print("Predeployed FeeToken")
+        print("Predeployed FeeToken" if self.is_fee_token else "Predeployed ERC20")
         print(f"Address: {hex(self.address)}")
         print(f"Class Hash: {hex(self.class_hash)}")
-        print(f"Symbol: {self.SYMBOL}\n")
+        print(f"Symbol: {self.symbol}\n")
         sys.stdout.flush()
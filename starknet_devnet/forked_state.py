@@ -11,7 +11,6 @@ from starkware.starknet.core.os.contract_class.compiled_class_hash import (
 )
 from starkware.starknet.definitions.constants import UNINITIALIZED_CLASS_HASH
 from starkware.starknet.definitions.error_codes import StarknetErrorCode
-from starkware.starknet.definitions.general_config import StarknetChainId
 from starkware.starknet.services.api.contract_class.contract_class import (
     CompiledClass,
     CompiledClassBase,
@@ -25,7 +24,7 @@ from starkware.starknet.testing.state import StarknetState
 from starkware.starkware_utils.error_handling import StarkException
 
 from .block_info_generator import now
-from .general_config import build_devnet_general_config
+from .general_config import ChainId, build_devnet_general_config
 from .util import StarknetDevnetException, suppress_feeder_gateway_client_logger
 
 
@@ -65,6 +64,9 @@ class ForkedStateReader(StateReader):
     ):
         self.__feeder_gateway_client = feeder_gateway_client
         self.__block_number = block_number
+        # the fork block is pinned, so fetched classes never change and can be cached
+        self.__class_cache = {}
+        self.__compiled_class_cache = {}
 
     async def get_compiled_class_by_class_hash(
         self, class_hash: int
@@ -93,12 +95,30 @@ class ForkedStateReader(StateReader):
 
         return compiled_class
 
-    async def _get_class_by_hash(self, class_hash: int) -> CompiledClassBase:
-        try:
+    async def _fetch_class_dict(self, class_hash: int) -> dict:
+        if class_hash not in self.__class_cache:
             with suppress_feeder_gateway_client_logger:
-                class_dict = await self.__feeder_gateway_client.get_class_by_hash(
+                self.__class_cache[
+                    class_hash
+                ] = await self.__feeder_gateway_client.get_class_by_hash(
                     class_hash=hex(class_hash), block_number=self.__block_number
                 )
+        return self.__class_cache[class_hash]
+
+    async def _fetch_compiled_class_dict(self, class_hash: int) -> dict:
+        if class_hash not in self.__compiled_class_cache:
+            with suppress_feeder_gateway_client_logger:
+                self.__compiled_class_cache[
+                    class_hash
+                ] = await self.__feeder_gateway_client.get_compiled_class_by_class_hash(
+                    hex(class_hash),
+                    block_number=self.__block_number,
+                )
+        return self.__compiled_class_cache[class_hash]
+
+    async def _get_class_by_hash(self, class_hash: int) -> CompiledClassBase:
+        try:
+            class_dict = await self._fetch_class_dict(class_hash)
             return _load_compiled_class(class_dict)
         except BadRequest as bad_request:
             if is_originally_starknet_exception(bad_request):
@@ -108,13 +128,9 @@ class ForkedStateReader(StateReader):
 
     async def get_compiled_class(self, compiled_class_hash: int) -> CompiledClassBase:
         try:
-            with suppress_feeder_gateway_client_logger:
-                compiled_class_dict = (
-                    await self.__feeder_gateway_client.get_compiled_class_by_class_hash(
-                        hex(compiled_class_hash),
-                        block_number=self.__block_number,
-                    )
-                )
+            compiled_class_dict = await self._fetch_compiled_class_dict(
+                compiled_class_hash
+            )
             return CompiledClass.load(compiled_class_dict)
         except BadRequest as bad_request:
             if is_originally_starknet_exception(bad_request):
@@ -126,13 +142,7 @@ class ForkedStateReader(StateReader):
 
     async def get_compiled_class_hash(self, class_hash: int) -> int:
         try:
-            with suppress_feeder_gateway_client_logger:
-                compiled_class_dict = (
-                    await self.__feeder_gateway_client.get_compiled_class_by_class_hash(
-                        hex(class_hash),
-                        block_number=self.__block_number,
-                    )
-                )
+            compiled_class_dict = await self._fetch_compiled_class_dict(class_hash)
             compiled_class = _load_compiled_class(compiled_class_dict)
         except BadRequest as bad_request:
             if is_originally_starknet_exception(bad_request):
@@ -186,7 +196,8 @@ def get_forked_starknet(
     feeder_gateway_client: FeederGatewayClient,
     block_number: int,
     gas_price: int,
-    chain_id: StarknetChainId,
+    chain_id: ChainId,
+    sequencer_address: int,
 ) -> Starknet:
     """Return a forked Starknet"""
     state_reader = ForkedStateReader(
@@ -204,6 +215,6 @@ def get_forked_starknet(
                 state_reader=state_reader,
                 compiled_class_cache={},
             ),
-            general_config=build_devnet_general_config(chain_id),
+            general_config=build_devnet_general_config(chain_id, sequencer_address),
         )
     )
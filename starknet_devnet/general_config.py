@@ -1,6 +1,9 @@
 """
 Contains general_config generation functionalities.
 """
+from dataclasses import dataclass
+from typing import Union
+
 from starkware.starknet.definitions import constants
 from starkware.starknet.definitions.general_config import (
     DEFAULT_GAS_PRICE,
@@ -8,15 +11,44 @@ from starkware.starknet.definitions.general_config import (
     DEFAULT_TX_MAX_STEPS,
     DEFAULT_VALIDATE_MAX_STEPS,
     StarknetChainId,
+    StarknetGeneralConfig,
     build_general_config,
 )
 
 from .fee_token import FeeToken
 
 
-def build_devnet_general_config(chain_id: StarknetChainId):
-    """General config build with chain id argument."""
-    return build_general_config(
+@dataclass(frozen=True)
+class CustomChainId:
+    """
+    A chain id not enumerated by StarknetChainId.
+    Mimics the enum members by providing `name` and `value`.
+    """
+
+    name: str
+    value: int
+
+
+ChainId = Union[StarknetChainId, CustomChainId]
+
+
+def to_chain_id(value: int, name: str) -> ChainId:
+    """Returns the known chain id with `value` or a custom one named `name`"""
+    try:
+        return StarknetChainId(value)
+    except ValueError:
+        return CustomChainId(name=name, value=value)
+
+
+def build_devnet_general_config(
+    chain_id: ChainId, sequencer_address: int = DEFAULT_SEQUENCER_ADDRESS
+):
+    """General config build with chain id and sequencer address arguments."""
+    # cairo-lang only loads the chain ids it enumerates; a custom one is set afterwards
+    loaded_chain_id = (
+        StarknetChainId.TESTNET if isinstance(chain_id, CustomChainId) else chain_id
+    )
+    general_config = build_general_config(
         {
             "cairo_resource_fee_weights": {
                 "n_steps": constants.N_STEPS_FEE_WEIGHT,
@@ -29,9 +61,9 @@ def build_devnet_general_config(chain_id: StarknetChainId):
             "invoke_tx_max_n_steps": DEFAULT_TX_MAX_STEPS,
             "min_gas_price": DEFAULT_GAS_PRICE,
             "constant_gas_price": True,
-            "sequencer_address": hex(DEFAULT_SEQUENCER_ADDRESS),
+            "sequencer_address": hex(sequencer_address),
             "starknet_os_config": {
-                "chain_id": chain_id.value,
+                "chain_id": loaded_chain_id.value,
                 "fee_token_address": hex(FeeToken.ADDRESS),
             },
             "tx_commitment_tree_height": constants.TRANSACTION_COMMITMENT_TREE_HEIGHT,
@@ -39,5 +71,20 @@ def build_devnet_general_config(chain_id: StarknetChainId):
         }
     )
 
+    if isinstance(chain_id, CustomChainId):
+        _set_custom_chain_id(general_config, chain_id)
+
+    return general_config
+
 
-DEFAULT_GENERAL_CONFIG = build_devnet_general_config(StarknetChainId.TESTNET)
+def _set_custom_chain_id(
+    general_config: StarknetGeneralConfig, chain_id: CustomChainId
+):
+    """
+    Set `chain_id` in the frozen `general_config` in place.
+    cairo-lang's config schema only accepts the enumerated chain ids, so a custom one
+    cannot be loaded through it. `CustomChainId` provides the `name` and `value` which
+    are read from the field. Dumping pickles the config, so the schema is not involved.
+    This is the only place where the frozen config is modified.
+    """
+    object.__setattr__(general_config.starknet_os_config, "chain_id", chain_id)
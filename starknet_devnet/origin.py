@@ -2,6 +2,10 @@
 Contains classes that provide the abstraction of L2 blockchain.
 """
 
+import asyncio
+import inspect
+from typing import List
+
 from services.external_api.client import BadRequest
 from starkware.starknet.definitions.error_codes import StarknetErrorCode
 from starkware.starknet.services.api.feeder_gateway.feeder_gateway_client import (
@@ -76,6 +80,15 @@ class Origin:
         raise NotImplementedError
 
 
+def get_forwarded_queries() -> List[str]:
+    """Returns the names of queries which can be answered by a forking origin"""
+    return [
+        name
+        for name, _ in inspect.getmembers(Origin, inspect.iscoroutinefunction)
+        if not name.startswith("_")
+    ]
+
+
 class NullOrigin(Origin):
     """
     A default class to comply with the Origin interface.
@@ -171,6 +184,20 @@ class ForkedOrigin(Origin):
         self.__feeder_gateway_client = feeder_gateway_client
         self.__number_of_blocks = last_block_number + 1
 
+    async def is_reachable(self, timeout: float) -> bool:
+        """
+        Returns whether the origin responds within `timeout` seconds.
+        Not listed among forwarded queries, as it's not defined on `Origin`.
+        """
+        try:
+            with suppress_feeder_gateway_client_logger:
+                await asyncio.wait_for(
+                    self.__feeder_gateway_client.is_alive(), timeout=timeout
+                )
+        except Exception:  # pylint: disable=broad-except
+            return False
+        return True
+
     async def get_transaction_status(self, transaction_hash: str):
         return await self.__feeder_gateway_client.get_transaction_status(
             transaction_hash
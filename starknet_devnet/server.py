@@ -7,20 +7,21 @@ import json
 import os
 import sys
 
-from flask import Flask, jsonify
+from flask import Flask, jsonify, request
 from flask_cors import CORS
 from gunicorn.app.base import BaseApplication
 from starkware.starkware_utils.error_handling import StarkErrorCode, StarkException
+from werkzeug.exceptions import RequestEntityTooLarge
 
 from .blueprints.base import base
 from .blueprints.feeder_gateway import feeder_gateway
 from .blueprints.gateway import gateway
 from .blueprints.postman import postman
 from .blueprints.rpc.routes import rpc
-from .devnet_config import DevnetConfig, DumpOn, parse_args
+from .devnet_config import DevnetConfig, DumpOn, LogFormat, parse_args
 from .starknet_wrapper import StarknetWrapper
 from .state import state
-from .util import StarknetDevnetException
+from .util import JsonLogFormatter, StarknetDevnetException
 
 app = Flask(__name__)
 CORS(app)
@@ -32,6 +33,28 @@ async def initialize_starknet():
     await state.starknet_wrapper.initialize()
 
 
+@app.after_request
+def dump_on_blocks(response):
+    """Dump if enough blocks have been created since the last dump."""
+    if state.dumper.dump_on == DumpOn.BLOCKS:
+        block_count = state.starknet_wrapper.blocks.get_number_of_accepted_blocks()
+        state.dumper.dump_if_block_count_reached(block_count)
+    return response
+
+
+@app.after_request
+def record_action(response):
+    """Record successful requests which perform Devnet-specific actions."""
+    if (
+        request.method == "POST"
+        and request.blueprint in (base.name, postman.name)
+        and response.status_code == 200
+    ):
+        params = request.get_json(silent=True) or request.args.to_dict()
+        state.starknet_wrapper.action_history.record(request.path, params)
+    return response
+
+
 app.register_blueprint(base)
 app.register_blueprint(gateway)
 app.register_blueprint(feeder_gateway)
@@ -52,28 +75,47 @@ class GunicornServer(BaseApplication):
         self.cfg.set("bind", f"{self.args.host}:{self.args.port}")
         self.cfg.set("workers", 1)
         self.cfg.set("timeout", self.args.timeout)
-        self.cfg.set(
-            "logconfig_dict",
-            {
-                "loggers": {
-                    "gunicorn.error": {
-                        "level": "INFO" if self.args.verbose else "WARNING",
-                        "handlers": ["error_console"],
-                        "propagate": False,
-                        "qualname": "gunicorn.error",
-                    },
-                    "gunicorn.access": {
-                        "level": "INFO"
-                        if self.args.verbose or not self.args.hide_server_logs
-                        else "WARNING",
-                        # Log access to stderr to maintain backward compatibility
-                        "handlers": ["error_console"],
-                        "propagate": False,
-                        "qualname": "gunicorn.access",
-                    },
+        self.cfg.set("logconfig_dict", self.__get_logconfig_dict())
+
+    def __get_logconfig_dict(self):
+        logconfig_dict = {
+            "loggers": {
+                "gunicorn.error": {
+                    "level": "INFO" if self.args.verbose else "WARNING",
+                    "handlers": ["error_console"],
+                    "propagate": False,
+                    "qualname": "gunicorn.error",
+                },
+                "gunicorn.access": {
+                    "level": "INFO"
+                    if self.args.verbose or not self.args.hide_server_logs
+                    else "WARNING",
+                    # Log access to stderr to maintain backward compatibility
+                    "handlers": ["error_console"],
+                    "propagate": False,
+                    "qualname": "gunicorn.access",
                 },
             },
-        )
+        }
+
+        if self.args.log_format == LogFormat.JSON:
+            # gunicorn only shallowly merges these into its defaults,
+            # so both of its handlers have to be redefined
+            logconfig_dict["formatters"] = {"json": {"()": JsonLogFormatter}}
+            logconfig_dict["handlers"] = {
+                "console": {
+                    "class": "logging.StreamHandler",
+                    "formatter": "json",
+                    "stream": "ext://sys.stdout",
+                },
+                "error_console": {
+                    "class": "logging.StreamHandler",
+                    "formatter": "json",
+                    "stream": "ext://sys.stderr",
+                },
+            }
+
+        return logconfig_dict
 
     def load(self):
         return self.application
@@ -90,11 +132,17 @@ def main():
         else:
             state.set_starknet_wrapper(StarknetWrapper(DevnetConfig(args)))
 
-        state.set_dump_options(args.dump_path, args.dump_on)
+        state.set_dump_options(args.dump_path, args.dump_on, args.dump_block_count)
     except StarknetDevnetException as error:
         sys.exit(error.message)
 
     asyncio.run(state.starknet_wrapper.initialize())
+    # blocks present on startup are not counted towards dumping on blocks
+    state.dumper.dumped_block_count = (
+        state.starknet_wrapper.blocks.get_number_of_accepted_blocks()
+    )
+
+    app.config["MAX_CONTENT_LENGTH"] = args.request_body_size_limit
 
     main_pid = os.getpid()
     print(f" * Listening on http://{args.host}:{args.port}/ (Press CTRL+C to quit)")
@@ -127,6 +175,17 @@ def handle_json_decode_error(error: json.decoder.JSONDecodeError):
     }, 400
 
 
+@app.errorhandler(RequestEntityTooLarge)
+def handle_request_entity_too_large(_: RequestEntityTooLarge):
+    """Handles request bodies exceeding the configured size limit"""
+    return {
+        "message": "Request body exceeds the limit of "
+        f"{app.config['MAX_CONTENT_LENGTH']} bytes; "
+        "it can be changed with --request-body-size-limit",
+        "code": str(StarkErrorCode.MALFORMED_REQUEST),
+    }, 413
+
+
 @app.route("/api", methods=["GET"])
 def api():
     """Return available endpoints."""
@@ -4,6 +4,7 @@ This module introduces `StarknetWrapper`, a wrapper class of
 starkware.starknet.testing.starknet.Starknet.
 """
 import asyncio
+import dataclasses
 import pprint
 from copy import deepcopy
 from types import TracebackType
@@ -34,6 +35,7 @@ from starkware.starknet.business_logic.utils import calculate_tx_resources
 from starkware.starknet.core.os.contract_address.contract_address import (
     calculate_contract_address_from_hash,
 )
+from starkware.starknet.core.os.contract_class.class_hash import compute_class_hash
 from starkware.starknet.core.os.contract_class.compiled_class_hash import (
     compute_compiled_class_hash,
 )
@@ -85,7 +87,9 @@ from starkware.starkware_utils.error_handling import (
     stark_assert_le,
 )
 
+from .account_util import set_balance
 from .accounts import Accounts
+from .action_history import ActionHistory
 from .block_info_generator import BlockInfoGenerator
 from .blocks import DevnetBlocks
 from .blueprints.rpc.structures.types import BlockId, Felt
@@ -120,10 +124,13 @@ from .util import (
     get_all_declared_cairo0_classes,
     get_all_declared_cairo1_classes,
     get_fee_estimation_info,
+    get_non_zero_storage,
+    get_nonce_diffs,
     get_replaced_classes,
     get_storage_diffs,
     group_classes_by_version,
     logger,
+    merge_state_diffs,
     stark_assert_call_succeeded,
     warn,
 )
@@ -136,6 +143,130 @@ DEFAULT_BLOCK_ID = LATEST_BLOCK_ID
 # pylint: disable=too-many-instance-attributes
 # pylint: disable=too-many-public-methods
 # pylint: disable=too-many-locals
+class TransactionValidator:
+    """Validations performed by Devnet before executing an account transaction"""
+
+    def __init__(
+        self,
+        starknet_wrapper: "StarknetWrapper",
+        internal_tx: InternalAccountTransaction,
+    ):
+        self.starknet_wrapper = starknet_wrapper
+        self.internal_tx = internal_tx
+
+    @staticmethod
+    def validate_max_fee(external_tx: AccountTransaction, allow_max_fee_zero: bool):
+        """Assert the max fee of `external_tx` is allowed"""
+        if (
+            external_tx.version != LEGACY_TX_VERSION
+            and external_tx.max_fee == 0
+            and not allow_max_fee_zero
+        ):
+            raise StarknetDevnetException(
+                code=StarknetErrorCode.OUT_OF_RANGE_FEE,
+                message="max_fee must be bigger than 0.",
+            )
+
+    async def validate(self, cached_state: CachedState):
+        """Perform the state-related validations on a copy of `cached_state`"""
+        # pylint: disable=protected-access
+        state = cached_state._copy()
+        loop = asyncio.get_running_loop()
+        state = UpdatesTrackerState(state=StateSyncifier(async_state=state, loop=loop))
+        await asyncio.to_thread(
+            self._inner_perform_state_related_validations, state=state
+        )
+
+    def _check_nonce(self, state: UpdatesTrackerState):
+        nonce = state.get_nonce_at(
+            storage_domain=StorageDomain.ON_CHAIN,
+            contract_address=self.internal_tx.sender_address,
+        )
+        # BACKWARD-COMPATIBILITY.
+        tx_nonce = 0 if self.internal_tx.nonce is None else self.internal_tx.nonce
+        stark_assert_le(
+            nonce,
+            tx_nonce,
+            code=StarknetErrorCode.INVALID_TRANSACTION_NONCE,
+            message="Transaction's nonce must be greater than or equal to the last known nonce.",
+        )
+
+    def _check_balance(self, state: UpdatesTrackerState):
+        balance = state.get_fee_token_balance(
+            storage_domain=StorageDomain.ON_CHAIN,
+            contract_address=self.internal_tx.sender_address,
+            fee_token_address=self.starknet_wrapper.fee_token.address,
+        )
+        stark_assert_le(
+            self.internal_tx.max_fee,
+            balance,
+            code=StarknetErrorCode.INSUFFICIENT_ACCOUNT_BALANCE,
+            message="Account balance must be greater or equal to the transaction's max_fee.",
+        )
+
+    def _validate(self, state: UpdatesTrackerState) -> ResourcesMapping:
+        if isinstance(self.internal_tx, InternalDeployAccount):
+            # Run the entire transaction since a constructor call must precede the `validate`.
+            tx_execution_info = self.internal_tx.apply_concurrent_changes(
+                state=state,
+                general_config=self.starknet_wrapper.get_state().general_config,
+            )
+            for call_info in tx_execution_info.non_optional_calls:
+                stark_assert_call_succeeded(call_info=call_info)
+
+            actual_resources = tx_execution_info.actual_resources
+        else:
+            resources_manager = ExecutionResourcesManager.empty()
+            validate_info, _ = self.internal_tx.run_validate_entrypoint(
+                state=state,
+                general_config=self.starknet_wrapper.get_state().general_config,
+                resources_manager=resources_manager,
+                remaining_gas=self.internal_tx.get_initial_gas(),
+            )
+            # Check can be removed when v0 transactions are disabled
+            if self.internal_tx.version > 0:
+                assert (
+                    validate_info is not None
+                ), "validate_info must be not None for version > 0."
+                stark_assert_call_succeeded(call_info=validate_info)
+
+            actual_resources = calculate_tx_resources(
+                state=state,
+                resources_manager=resources_manager,
+                call_infos=[validate_info],
+                tx_type=self.internal_tx.tx_type,
+                fee_token_address=self.starknet_wrapper.fee_token.address,
+                is_nonce_increment=self.internal_tx.version > 0,
+                sender_address=self.internal_tx.sender_address,
+            )
+
+        return actual_resources
+
+    def _check_validation_fee(
+        self, state: UpdatesTrackerState, actual_resources: ResourcesMapping
+    ):
+        # Check that max_fee is high enough to pay for the validation.
+        actual_fee = calculate_tx_fee(
+            gas_price=state.block_info.gas_price,
+            general_config=self.starknet_wrapper.get_state().general_config,
+            resources=actual_resources,
+        )
+
+        stark_assert_le(
+            actual_fee,
+            self.internal_tx.max_fee,
+            code=StarknetErrorCode.INSUFFICIENT_MAX_FEE,
+            message="Max fee must be greater or equal to the validation's actual fee.",
+        )
+
+    def _inner_perform_state_related_validations(self, state: UpdatesTrackerState):
+        self._check_nonce(state)
+        self._check_balance(state)
+        validation_resources = self._validate(state)
+        if self.internal_tx.max_fee:
+            self._check_validation_fee(state, validation_resources)
+
+
 class StarknetWrapper:
     """
     Wraps a Starknet instance and stores data to be returned by the server:
@@ -150,7 +281,9 @@ class StarknetWrapper:
         )
         """Origin chain that this devnet was forked from."""
 
-        self.block_info_generator = BlockInfoGenerator()
+        self.block_info_generator = BlockInfoGenerator(
+            block_time_increment=config.block_time_increment
+        )
         self.blocks = None
         self.config = config
         self.l1l2 = DevnetL1L2()
@@ -159,6 +292,10 @@ class StarknetWrapper:
         self.__current_cached_state = None
         self.__initialized = False
         self.fee_token = FeeToken(self)
+        self.predeployed_erc20s = [
+            FeeToken(self, name=name, symbol=symbol, address=address)
+            for name, symbol, address in config.predeployed_erc20s
+        ]
         self.accounts = Accounts(self)
         self.__udc = UDC(self)
         self.pending_txs: List[DevnetTransaction] = []
@@ -167,6 +304,9 @@ class StarknetWrapper:
         """If v2 - store sierra, otherwise store old class; needed for get_class_by_hash"""
         self.genesis_block_number = None
         self._compiler = select_compiler(config)
+        self.__next_transaction_hash: Optional[int] = None
+        """If set, used instead of the computed hash of the next transaction"""
+        self.action_history = ActionHistory(config.action_history_size)
 
         if config.start_time is not None:
             self.set_block_time(config.start_time)
@@ -185,10 +325,19 @@ class StarknetWrapper:
             starknet = await self.__init_starknet()
 
             # ok that it's here so that e.g. reset includes reset of blocks
-            self.blocks = DevnetBlocks(self.origin, lite=self.config.lite_mode)
+            self.blocks = DevnetBlocks(
+                self.origin,
+                lite=self.config.lite_mode,
+                instant_l1_acceptance=self.config.instant_l1_acceptance,
+                genesis_parent_hash=self.config.genesis_parent_hash,
+                state_archive_limit=self.config.state_archive_limit,
+                starting_block_number=self.config.starting_block_number,
+            )
 
             self._contract_classes = {}
             await self.fee_token.deploy()
+            for token in self.predeployed_erc20s:
+                await token.deploy()
             await self.accounts.deploy()
             await self.__deploy_chargeable_account()
             await self.__predeclare_starknet_cli_account()
@@ -232,6 +381,8 @@ class StarknetWrapper:
             (UDC.HASH, UDC.ADDRESS),
             (self.config.account_class.hash, ChargeableAccount.ADDRESS),
         ]
+        for token in self.predeployed_erc20s:
+            deploy_data.append((token.class_hash, token.address))
         for account in self.accounts:
             deploy_data.append((account.class_hash, account.address))
 
@@ -256,6 +407,8 @@ class StarknetWrapper:
         await self.generate_latest_block(block_hash=0)
 
         for transaction in transactions:
+            if self.config.instant_l1_acceptance:
+                transaction.accept_on_l1()
             self.transactions.store(transaction.transaction_hash, transaction)
 
     async def create_empty_block(self) -> StarknetBlock:
@@ -283,11 +436,21 @@ class StarknetWrapper:
                     block_number=self.config.fork_block,
                     gas_price=self.block_info_generator.gas_price,
                     chain_id=self.config.chain_id,
+                    sequencer_address=self.config.sequencer_address,
                 )
             else:
                 self.starknet = await Starknet.empty(
-                    general_config=build_devnet_general_config(self.config.chain_id)
+                    general_config=build_devnet_general_config(
+                        self.config.chain_id, self.config.sequencer_address
+                    )
                 )
+                if self.config.starting_block_number:
+                    # incremented right before creating the genesis block
+                    cached_state = self.starknet.state.state
+                    cached_state.block_info = dataclasses.replace(
+                        cached_state.block_info,
+                        block_number=self.config.starting_block_number - 1,
+                    )
 
         return self.starknet
 
@@ -341,15 +504,21 @@ class StarknetWrapper:
         storage_diffs = await get_storage_diffs(
             previous_state, current_state, visited_storage_entries
         )
+        nonce_diffs = await get_nonce_diffs(previous_state, current_state)
         state_diff = StateDiff(
             deployed_contracts=deployed_contracts,
             old_declared_contracts=old_declared_contracts,
             declared_classes=declared_classes,
             replaced_classes=replaced,
             storage_diffs=storage_diffs,
-            nonces=nonces or {},
+            nonces={**nonce_diffs, **(nonces or {})},
         )
 
+        # the pending block accumulates the changes of all of its transactions
+        pending_state_update = self.blocks.get_pending_state_update()
+        if pending_state_update is not None:
+            state_diff = merge_state_diffs(pending_state_update.state_diff, state_diff)
+
         return BlockStateUpdate(
             block_hash=DUMMY_PENDING_BLOCK_HASH,
             new_root=DUMMY_STATE_ROOT,
@@ -358,15 +527,37 @@ class StarknetWrapper:
         )
 
     async def declare(
-        self, external_tx: Union[Declare, DeprecatedDeclare]
+        self,
+        external_tx: Union[Declare, DeprecatedDeclare],
+        use_next_transaction_hash=False,
     ) -> Tuple[int, int]:
         """
         Declares the class specified with `declare_transaction`
         Returns (class_hash, transaction_hash)
+        If `use_next_transaction_hash`, the hash set for the next transaction is used.
         """
 
+        if self.config.lenient_declare and isinstance(external_tx, Declare):
+            class_hash = compute_class_hash(external_tx.contract_class)
+            declaration_hash = self.transactions.get_declaration_hash(class_hash)
+            if declaration_hash is not None:
+                # validated like any declaration; only the redeclaration is skipped
+                TransactionValidator.validate_max_fee(
+                    external_tx, self.config.allow_max_fee_zero
+                )
+                internal_tx = InternalAccountTransaction.from_external(
+                    external_tx, self.get_state().general_config
+                )
+                await TransactionValidator(self, internal_tx).validate(
+                    self.get_state().state
+                )
+                logger.info("Class %s is already declared", hex(class_hash))
+                return class_hash, declaration_hash
+
         state = self.get_state()
-        async with self.__get_transaction_handler(external_tx) as tx_handler:
+        async with self.__get_transaction_handler(
+            external_tx, self.__get_tx_hash_override(use_next_transaction_hash)
+        ) as tx_handler:
             # extract class hash here if execution later fails
             class_hash = tx_handler.internal_tx.class_hash
 
@@ -410,8 +601,40 @@ class StarknetWrapper:
             state.state.compiled_classes[compiled_class_hash] = compiled_class
             self._contract_classes[class_hash] = external_tx.contract_class
 
+        self.__clear_used_next_transaction_hash(tx_handler.internal_tx.hash_value)
         return class_hash, tx_handler.internal_tx.hash_value
 
+    def set_next_transaction_hash(self, tx_hash: int):
+        """Make the next submitted transaction have `tx_hash` instead of its computed hash"""
+        if self.transactions.contains(tx_hash):
+            raise StarknetDevnetException(
+                code=StarknetErrorCode.DUPLICATED_TRANSACTION,
+                message=f"Transaction with hash {hex(tx_hash)} already exists.",
+                status_code=400,
+            )
+
+        self.__next_transaction_hash = tx_hash
+
+    def __get_tx_hash_override(self, use_next_transaction_hash: bool):
+        return self.__next_transaction_hash if use_next_transaction_hash else None
+
+    def __clear_used_next_transaction_hash(self, tx_hash: int):
+        """Clear the hash set for the next transaction if it was given to `tx_hash`"""
+        if tx_hash == self.__next_transaction_hash:
+            self.__next_transaction_hash = None
+
+    def get_erc20(self, address: int) -> FeeToken:
+        """Return the fee token or the predeployed ERC20 token at `address`"""
+        for token in [self.fee_token, *self.predeployed_erc20s]:
+            if token.address == address:
+                return token
+
+        raise StarknetDevnetException(
+            code=StarknetErrorCode.UNINITIALIZED_CONTRACT,
+            message=f"No predeployed ERC20 token at address {hex(address)}.",
+            status_code=400,
+        )
+
     def _update_block_number(self):
         """Updates just the block number. Returns the old block info to allow reverting"""
         current_cached_state = self.get_state().state
@@ -426,7 +649,9 @@ class StarknetWrapper:
         return block_info
 
     def __get_transaction_handler(  # pylint: disable=too-many-statements
-        self, external_tx: Optional[AccountTransaction] = None
+        self,
+        external_tx: Optional[AccountTransaction] = None,
+        tx_hash_override: Optional[int] = None,
     ):
         class TransactionHandler:
             """Class for with-blocks in transactions"""
@@ -434,6 +659,7 @@ class StarknetWrapper:
             internal_tx: Optional[InternalAccountTransaction] = None
             execution_info: TransactionExecutionInfo = TransactionExecutionInfo.empty()
             internal_calls: List[CallInfo] = []
+            transaction: Optional[DevnetTransaction] = None
             deployed_contracts: List[ContractAddressHashPair] = []
             explicitly_declared_old: List[int] = []
             explicitly_declared: List[ClassHashPair] = []
@@ -443,126 +669,44 @@ class StarknetWrapper:
                 self.starknet_wrapper = starknet_wrapper
                 self.preserved_block_info = starknet_wrapper._update_block_number()
                 if external_tx:
-                    self._validate_fee(external_tx)
+                    TransactionValidator.validate_max_fee(
+                        external_tx, starknet_wrapper.config.allow_max_fee_zero
+                    )
                     self.internal_tx = InternalAccountTransaction.from_external(
                         external_tx, starknet_wrapper.get_state().general_config
                     )
 
-            def _check_nonce(self, state: UpdatesTrackerState):
-                nonce = state.get_nonce_at(
-                    storage_domain=StorageDomain.ON_CHAIN,
-                    contract_address=self.internal_tx.sender_address,
-                )
-                # BACKWARD-COMPATIBILITY.
-                tx_nonce = (
-                    0 if self.internal_tx.nonce is None else self.internal_tx.nonce
-                )
-                stark_assert_le(
-                    nonce,
-                    tx_nonce,
-                    code=StarknetErrorCode.INVALID_TRANSACTION_NONCE,
-                    message="Transaction's nonce must be greater than or equal to the last known nonce.",
-                )
-
-            def _check_balance(self, state: UpdatesTrackerState):
-                balance = state.get_fee_token_balance(
-                    storage_domain=StorageDomain.ON_CHAIN,
-                    contract_address=self.internal_tx.sender_address,
-                    fee_token_address=self.starknet_wrapper.fee_token.address,
-                )
-                stark_assert_le(
-                    self.internal_tx.max_fee,
-                    balance,
-                    code=StarknetErrorCode.INSUFFICIENT_ACCOUNT_BALANCE,
-                    message="Account balance must be greater or equal to the transaction's max_fee.",
-                )
-
-            def _validate(self, state: UpdatesTrackerState) -> ResourcesMapping:
-                if isinstance(self.internal_tx, InternalDeployAccount):
-                    # Run the entire transaction since a constructor call must precede the `validate`.
-                    tx_execution_info = self.internal_tx.apply_concurrent_changes(
-                        state=state,
-                        general_config=self.starknet_wrapper.get_state().general_config,
-                    )
-                    for call_info in tx_execution_info.non_optional_calls:
-                        stark_assert_call_succeeded(call_info=call_info)
-
-                    actual_resources = tx_execution_info.actual_resources
-                else:
-                    resources_manager = ExecutionResourcesManager.empty()
-                    validate_info, _ = self.internal_tx.run_validate_entrypoint(
-                        state=state,
-                        general_config=self.starknet_wrapper.get_state().general_config,
-                        resources_manager=resources_manager,
-                        remaining_gas=self.internal_tx.get_initial_gas(),
-                    )
-                    # Check can be removed when v0 transactions are disabled
-                    if self.internal_tx.version > 0:
-                        assert (
-                            validate_info is not None
-                        ), "validate_info must be not None for version > 0."
-                        stark_assert_call_succeeded(call_info=validate_info)
-
-                    actual_resources = calculate_tx_resources(
-                        state=state,
-                        resources_manager=resources_manager,
-                        call_infos=[validate_info],
-                        tx_type=self.internal_tx.tx_type,
-                        fee_token_address=self.starknet_wrapper.fee_token.address,
-                        is_nonce_increment=self.internal_tx.version > 0,
-                        sender_address=self.internal_tx.sender_address,
-                    )
-
-                return actual_resources
-
-            def _check_validation_fee(
-                self, state: UpdatesTrackerState, actual_resources: ResourcesMapping
-            ):
-                # Check that max_fee is high enough to pay for the validation.
-                actual_fee = calculate_tx_fee(
-                    gas_price=state.block_info.gas_price,
-                    general_config=self.starknet_wrapper.get_state().general_config,
-                    resources=actual_resources,
-                )
-
-                stark_assert_le(
-                    actual_fee,
-                    self.internal_tx.max_fee,
-                    code=StarknetErrorCode.INSUFFICIENT_MAX_FEE,
-                    message="Max fee must be greater or equal to the validation's actual fee.",
-                )
-
-            def _validate_fee(self, external_tx: AccountTransaction):
-                if (
-                    external_tx.version != LEGACY_TX_VERSION
-                    and external_tx.max_fee == 0
-                    and not self.starknet_wrapper.config.allow_max_fee_zero
-                ):
-                    raise StarknetDevnetException(
-                        code=StarknetErrorCode.OUT_OF_RANGE_FEE,
-                        message="max_fee must be bigger than 0.",
-                    )
-
             async def __aenter__(self):
                 if self.internal_tx:
-                    state = self.starknet_wrapper.get_state().state._copy()
-                    loop = asyncio.get_running_loop()
-                    state = UpdatesTrackerState(
-                        state=StateSyncifier(async_state=state, loop=loop)
-                    )
-                    await asyncio.to_thread(
-                        self._inner_perform_state_related_validations, state=state
+                    validator = TransactionValidator(
+                        self.starknet_wrapper, self.internal_tx
                     )
+                    try:
+                        await validator.validate(
+                            self.starknet_wrapper.get_state().state
+                        )
+                    except StarkException as exc:
+                        if self.starknet_wrapper.config.store_rejected:
+                            self._store_rejected(exc)
+                        raise
                 return self
 
-            def _inner_perform_state_related_validations(
-                self, state: UpdatesTrackerState
-            ):
-                self._check_nonce(state)
-                self._check_balance(state)
-                validation_resources = self._validate(state)
-                if self.internal_tx.max_fee:
-                    self._check_validation_fee(state, validation_resources)
+            def _store_rejected(self, exc: StarkException):
+                # never added to pending txs, so it cannot become part of a block
+                transaction = DevnetTransaction(
+                    internal_tx=self.internal_tx,
+                    status=TransactionStatus.REJECTED,
+                    execution_status=ExecutionStatus.REJECTED,
+                    finality_status=FinalityStatus.RECEIVED,
+                    execution_info=TransactionExecutionInfo.empty(),
+                    transaction_hash=self.internal_tx.hash_value,
+                    block_number=None,
+                    transaction_index=None,
+                )
+                transaction.set_failure_reason(exc.message)
+                self.starknet_wrapper.transactions.store(
+                    transaction.transaction_hash, transaction
+                )
 
             async def __aexit__(
                 self,
@@ -571,6 +715,11 @@ class StarknetWrapper:
                 traceback: Optional[TracebackType],
             ):
                 assert self.internal_tx is not None
+                if not exc_type and tx_hash_override is not None:
+                    # only replaced after execution so that signature validation still passes
+                    self.internal_tx = dataclasses.replace(
+                        self.internal_tx, hash_value=tx_hash_override
+                    )
                 tx_hash = self.internal_tx.hash_value
 
                 if exc_type:
@@ -638,6 +787,7 @@ class StarknetWrapper:
                     if not self.starknet_wrapper.config.blocks_on_demand:
                         await self.starknet_wrapper.generate_latest_block()
 
+                self.transaction = transaction
                 logger.info(
                     "transaction execution info: %s",
                     pprint.pformat(self.execution_info.dump()),
@@ -650,8 +800,13 @@ class StarknetWrapper:
 
         return TransactionHandler(self)
 
-    async def deploy_account(self, external_tx: DeployAccount):
-        """Deploys account and returns (address, tx_hash)"""
+    async def deploy_account(
+        self, external_tx: DeployAccount, use_next_transaction_hash=False
+    ):
+        """
+        Deploys account and returns (address, tx_hash)
+        If `use_next_transaction_hash`, the hash set for the next transaction is used.
+        """
 
         account_address = calculate_contract_address_from_hash(
             salt=external_tx.contract_address_salt,
@@ -660,24 +815,56 @@ class StarknetWrapper:
             deployer_address=0,
         )
 
-        async with self.__get_transaction_handler(
-            external_tx=external_tx
-        ) as tx_handler:
-            tx_handler.execution_info = await self.__deploy(tx_handler.internal_tx)
-            tx_handler.internal_calls = (
-                tx_handler.execution_info.call_info.internal_calls
-            )
+        state = self.get_state()
+        auto_fund_amount = self.config.auto_fund_deploy_account
+        auto_funded = (
+            auto_fund_amount
+            and await self.fee_token.get_balance(account_address) == 0
+        )
+        if auto_funded:
+            # written directly to state so that no extra transaction or block is created
+            logger.info("Funding the unfunded address %s", hex(account_address))
+            await set_balance(state, account_address, auto_fund_amount)
+
+        try:
+            async with self.__get_transaction_handler(
+                external_tx=external_tx,
+                tx_hash_override=self.__get_tx_hash_override(use_next_transaction_hash),
+            ) as tx_handler:
+                tx_handler.execution_info = await self.__deploy(tx_handler.internal_tx)
+                tx_handler.internal_calls = (
+                    tx_handler.execution_info.call_info.internal_calls
+                )
+        except StarkException:
+            if auto_funded:
+                # the transaction was rejected, so the funding is undone
+                await set_balance(state, account_address, 0)
+            raise
 
+        if (
+            auto_funded
+            and tx_handler.transaction.execution_status == ExecutionStatus.REVERTED
+        ):
+            # the account was not deployed, so the funding is undone
+            await set_balance(state, account_address, 0)
+
+        self.__clear_used_next_transaction_hash(tx_handler.internal_tx.hash_value)
         return (
             account_address,
             tx_handler.internal_tx.hash_value,
         )
 
-    async def invoke(self, external_tx: InvokeFunction):
-        """Perform invoke according to specifications in `transaction`."""
+    async def invoke(
+        self, external_tx: InvokeFunction, use_next_transaction_hash=False
+    ):
+        """
+        Perform invoke according to specifications in `transaction`.
+        If `use_next_transaction_hash`, the hash set for the next transaction is used.
+        """
         state = self.get_state()
         async with self.__get_transaction_handler(
-            external_tx=external_tx
+            external_tx=external_tx,
+            tx_hash_override=self.__get_tx_hash_override(use_next_transaction_hash),
         ) as tx_handler:
             tx_handler.execution_info = await state.execute_tx(tx_handler.internal_tx)
             tx_handler.internal_calls = (
@@ -687,8 +874,45 @@ class StarknetWrapper:
                 tx_handler.execution_info.get_visited_storage_entries()
             )
 
+        self.__clear_used_next_transaction_hash(tx_handler.internal_tx.hash_value)
         return external_tx.sender_address, tx_handler.internal_tx.hash_value
 
+    async def dry_run(self, external_tx: AccountTransaction) -> DevnetTransaction:
+        """
+        Execute `external_tx` on a copy of the pending state and return the would-be transaction.
+        Nothing is stored and no block is generated.
+        The transaction is validated just like a submitted one.
+        Declaring is not supported.
+        """
+        TransactionValidator.validate_max_fee(
+            external_tx, self.config.allow_max_fee_zero
+        )
+
+        state = self.get_state().copy()
+        block_info = state.state.block_info
+        state.state.block_info = dataclasses.replace(
+            block_info, block_number=block_info.block_number + 1
+        )
+
+        internal_tx = InternalAccountTransaction.from_external(
+            external_tx, state.general_config
+        )
+        await TransactionValidator(self, internal_tx).validate(state.state)
+        execution_info = await state.execute_tx(internal_tx)
+
+        return DevnetTransaction(
+            internal_tx=internal_tx,
+            status=TransactionStatus.ACCEPTED_ON_L2,
+            execution_status=ExecutionStatus.REVERTED
+            if execution_info.revert_error
+            else ExecutionStatus.SUCCEEDED,
+            finality_status=FinalityStatus.ACCEPTED_ON_L2,
+            execution_info=execution_info,
+            block_number=self.blocks.get_next_block_number(),
+            transaction_index=len(self.pending_txs),
+            revert_error=execution_info.revert_error,
+        )
+
     async def __get_query_state(self, block_id: BlockId = DEFAULT_BLOCK_ID):
         if block_id == PENDING_BLOCK_ID:
             return self.get_state()
@@ -861,6 +1085,22 @@ class StarknetWrapper:
             )
         )
 
+    async def get_contract_storage(
+        self, contract_address: int, block_id: BlockId = DEFAULT_BLOCK_ID
+    ) -> Dict[int, int]:
+        """
+        Returns the non-zero storage of the contract at `contract_address`
+        as a mapping of keys to values.
+        """
+        state = await self.__get_query_state(block_id)
+        if not await state.state.get_class_hash_at(contract_address):
+            raise StarknetDevnetException(
+                code=StarknetErrorCode.UNINITIALIZED_CONTRACT,
+                message=f"No contract deployed at address {hex(contract_address)}.",
+            )
+
+        return get_non_zero_storage(state.state, contract_address)
+
     async def load_messaging_contract_in_l1(
         self, network_url: str, contract_address: str, network_id: str
     ) -> dict:
@@ -884,6 +1124,27 @@ class StarknetWrapper:
         state.consume_message_hash(message_hash=message_hash)
         return message_hash
 
+    async def send_message_to_l1(
+        self, from_address: int, to_address: int, payload: List[int]
+    ) -> str:
+        """Records a message to L1 as if it was sent by a transaction."""
+        state = self.get_state()
+
+        starknet_message = StarknetMessageToL1(
+            from_address=from_address,
+            to_address=to_address,
+            payload=payload,
+        )
+        message_hash = starknet_message.get_hash()
+
+        # same bookkeeping as done by StarknetState for executed transactions
+        state.l2_to_l1_messages_log.append(starknet_message)
+        # pylint: disable=protected-access
+        state._l2_to_l1_messages[message_hash] = (
+            state._l2_to_l1_messages.get(message_hash, 0) + 1
+        )
+        return message_hash
+
     async def mock_message_to_l2(self, transaction: InternalL1Handler) -> dict:
         """Handles L1 to L2 message mock endpoint"""
 
@@ -947,6 +1208,8 @@ class StarknetWrapper:
 
         for transaction in self.pending_txs:
             transaction.status = TransactionStatus.ACCEPTED_ON_L2
+            if self.config.instant_l1_acceptance:
+                transaction.accept_on_l1()
             transaction.set_block(block=block)
 
         # Update latest state before block generation
@@ -961,10 +1224,14 @@ class StarknetWrapper:
         external_tx: InvokeFunction,
         skip_validate: bool,
         block_id: BlockId = DEFAULT_BLOCK_ID,
+        gas_price: Optional[int] = None,
     ):
         """Calculates trace and fee by simulating tx on state copy."""
         traces, fees = await self.calculate_traces_and_fees(
-            [external_tx], skip_validate=skip_validate, block_id=block_id
+            [external_tx],
+            skip_validate=skip_validate,
+            block_id=block_id,
+            gas_price=gas_price,
         )
         assert len(traces) == len(fees) == 1
         return traces[0], fees[0]
@@ -974,9 +1241,22 @@ class StarknetWrapper:
         external_txs: List[InvokeFunction],
         skip_validate: bool,
         block_id: BlockId = DEFAULT_BLOCK_ID,
+        gas_price: Optional[int] = None,
     ):
         """Calculates traces and fees by simulating tx on state copy.
-        Uses the resulting state for each consecutive estimation"""
+        Uses the resulting state for each consecutive estimation.
+        If `gas_price` is provided, it is used instead of the gas price of the block;
+        only the feeder gateway provides it, as JSON-RPC has no such parameter"""
+        if len(external_txs) > self.config.max_batch_size:
+            raise StarknetDevnetException(
+                code=StarkErrorCode.MALFORMED_REQUEST,
+                status_code=400,
+                message=(
+                    f"Too many transactions in a single request: {len(external_txs)}; "
+                    f"the maximum is {self.config.max_batch_size}."
+                ),
+            )
+
         state = await self.__get_query_state(block_id)
         cached_state_copy = state.state
 
@@ -986,6 +1266,10 @@ class StarknetWrapper:
         for external_tx in external_txs:
             # pylint: disable=protected-access
             cached_state_copy = cached_state_copy._copy()
+            if gas_price is not None:
+                cached_state_copy.block_info = dataclasses.replace(
+                    cached_state_copy.block_info, gas_price=gas_price
+                )
             try:
                 internal_tx = InternalInvokeFunctionForSimulate.create_for_simulate(
                     external_tx,
@@ -1020,7 +1304,7 @@ class StarknetWrapper:
             traces.append(trace)
 
             fee_estimation_info = get_fee_estimation_info(
-                execution_info.actual_fee, state.state.block_info.gas_price
+                execution_info.actual_fee, cached_state_copy.block_info.gas_price
             )
             fee_estimation_infos.append(fee_estimation_info)
 
@@ -1061,6 +1345,107 @@ class StarknetWrapper:
         """Sets the block time to `time_s`."""
         self.block_info_generator.set_next_block_time(time_s)
 
+    def set_block_number(self, block_number: int):
+        """Sets the block number seen by the subsequently executed transactions"""
+        current_state = self.get_state().state
+        next_block_number = current_state.block_info.block_number + 1
+        if block_number < next_block_number:
+            raise StarknetDevnetException(
+                code=StarkErrorCode.MALFORMED_REQUEST,
+                message=(
+                    "Block number can only be increased; the next transaction would be "
+                    f"executed with {next_block_number}, got: {block_number}."
+                ),
+                status_code=400,
+            )
+
+        # the number is incremented right before executing a transaction
+        current_state.block_info = dataclasses.replace(
+            current_state.block_info, block_number=block_number - 1
+        )
+
+    async def __commit_direct_state_change(self, **state_change):
+        """
+        Include a change written directly to the state (i.e. not by a transaction)
+        in the pending block. Unless blocks are created on demand, the block is
+        generated right away and returned.
+        `state_change` is passed on to `update_pending_state`.
+        """
+        self._update_block_number()
+        state_update = await self.update_pending_state(**state_change)
+        await self.update_pending_block(state_update)
+
+        if self.config.blocks_on_demand:
+            return None
+        return await self.generate_latest_block()
+
+    async def set_blocks_on_demand(
+        self, blocks_on_demand: bool
+    ) -> Optional[StarknetBlock]:
+        """
+        Switch between generating blocks on demand and with each transaction.
+        When switching to the latter, the pending block (if any) is generated
+        right away and returned.
+        """
+        self.config.blocks_on_demand = blocks_on_demand
+        if blocks_on_demand or not self.blocks.is_block_pending():
+            return None
+        return await self.generate_latest_block()
+
+    async def __assert_deployed(self, contract_address: int):
+        if not await self.is_deployed(contract_address):
+            raise StarknetDevnetException(
+                code=StarknetErrorCode.UNINITIALIZED_CONTRACT,
+                message=f"No contract deployed at address {hex(contract_address)}.",
+            )
+
+    async def set_storage_at(
+        self, contract_address: int, key: int, value: int
+    ) -> Optional[StarknetBlock]:
+        """
+        Writes `value` to the storage of the contract at `contract_address` under `key`,
+        without executing a transaction. Returns the generated block, if any.
+        """
+        await self.__assert_deployed(contract_address)
+
+        await self.get_state().state.set_storage_at(
+            StorageDomain.ON_CHAIN, contract_address, key, value
+        )
+        return await self.__commit_direct_state_change(
+            visited_storage_entries={(contract_address, key)}
+        )
+
+    async def set_nonce(
+        self, contract_address: int, nonce: int
+    ) -> Optional[StarknetBlock]:
+        """
+        Sets the nonce of the contract at `contract_address` to `nonce`, which may also
+        be lower than the current one. Returns the generated block, if any.
+        """
+        await self.__assert_deployed(contract_address)
+
+        # CachedState only supports incrementing the nonce
+        # pylint: disable=protected-access
+        self.get_state().state.cache._nonce_writes[contract_address] = nonce
+        return await self.__commit_direct_state_change()
+
+    async def replace_class(
+        self, contract_address: int, class_hash: int
+    ) -> Optional[StarknetBlock]:
+        """
+        Replaces the class of the contract at `contract_address` with the declared
+        class `class_hash`, without executing a transaction.
+        Returns the generated block, if any.
+        """
+        await self.__assert_deployed(contract_address)
+        # raises if the class is not declared
+        await self.get_class_by_hash(class_hash)
+
+        await self.get_state().state.set_class_hash_at(
+            contract_address=contract_address, class_hash=class_hash
+        )
+        return await self.__commit_direct_state_change()
+
     def __set_gas_price(self, gas_price: int):
         """Sets gas price to `gas_price`."""
         self.block_info_generator.set_gas_price(gas_price)
@@ -1093,27 +1478,67 @@ class StarknetWrapper:
         class_hash = await cached_state.get_class_hash_at(address)
         return bool(class_hash)
 
-    async def abort_blocks(self, starting_block: StarknetBlock) -> str:
+    async def abort_latest_blocks(self, count: int) -> List[str]:
         """
-        Abort blocks.
+        Abort the `count` latest blocks. The pending block (if any) is generated
+        first, so it is counted among them.
         """
+        last_block_number = self.blocks.get_next_block_number() - 1
+        if self.blocks.is_block_pending():
+            last_block_number += 1
+
+        abortable_count = last_block_number - self.genesis_block_number
+        if count > abortable_count:
+            raise StarknetDevnetException(
+                code=StarknetErrorCode.OUT_OF_RANGE_BLOCK_ID,
+                message=f"Cannot abort {count} blocks: only {abortable_count} "
+                "blocks were created after the genesis block, "
+                "which cannot be aborted.",
+            )
+
+        starting_block_number = last_block_number - count + 1
+        if self.blocks.is_block_pending() and count == 1:
+            # only the pending block is aborted; it is generated on top of the latest
+            parent_block_hash = (await self.blocks.get_last_block()).block_hash
+        else:
+            starting_block = await self.blocks.get_by_number(starting_block_number)
+            parent_block_hash = starting_block.parent_block_hash
+        self.__assert_blocks_abortable(starting_block_number, parent_block_hash)
+
+        if self.blocks.is_block_pending():
+            await self.generate_latest_block()
+        starting_block = await self.blocks.get_by_number(starting_block_number)
+        return await self.abort_blocks(starting_block)
+
+    def __assert_blocks_abortable(
+        self, starting_block_number: int, parent_block_hash: int
+    ):
+        """Fail if blocks starting with `starting_block_number` cannot be aborted"""
         # Check if genesis block can be aborted.
-        if starting_block.block_number == self.genesis_block_number:
+        if starting_block_number == self.genesis_block_number:
             raise StarknetDevnetException(
                 code=StarknetErrorCode.OUT_OF_RANGE_BLOCK_ID,
                 message="Aborting genesis block is not supported.",
             )
 
         # Check if blocks can be aborted in fork mode.
-        if (
-            self.config.fork_block
-            and self.config.fork_block >= starting_block.block_number
-        ):
+        if self.config.fork_block and self.config.fork_block >= starting_block_number:
             raise StarknetDevnetException(
                 code=StarknetErrorCode.OUT_OF_RANGE_BLOCK_ID,
                 message="Aborting forked blocks is not supported.",
             )
 
+        # Fail before aborting anything if the state to revert to is not stored.
+        self.blocks.get_state(parent_block_hash)
+
+    async def abort_blocks(self, starting_block: StarknetBlock) -> List[str]:
+        """
+        Abort blocks.
+        """
+        self.__assert_blocks_abortable(
+            starting_block.block_number, starting_block.parent_block_hash
+        )
+
         # Create new block with pending transactions if possible.
         # We need to store them so later we can change the status to REJECTED.
         if self.blocks.is_block_pending():
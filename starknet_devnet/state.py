@@ -41,7 +41,9 @@ class State:
                 code=StarkErrorCode.INVALID_REQUEST, message=message, status_code=400
             ) from error
 
-    def set_dump_options(self, dump_path: str, dump_on: str):
+    def set_dump_options(
+        self, dump_path: str, dump_on: str, dump_block_count: int = None
+    ):
         """Assign dumping options from args to state."""
         if dump_path:
             try:
@@ -53,6 +55,7 @@ class State:
 
         self.dumper.dump_path = dump_path
         self.dumper.dump_on = dump_on
+        self.dumper.dump_block_count = dump_block_count
 
 
 state = State()
@@ -3,6 +3,7 @@ Stores Starknet states
 """
 
 import shelve
+from typing import Optional
 
 from starkware.starknet.definitions.error_codes import StarknetErrorCode
 from starkware.starknet.testing.state import StarknetState
@@ -48,18 +49,25 @@ class StateArchive:
 
 class MemoryStateArchive(StateArchive):
     """
-    Stores Starknet states in memory
+    Stores Starknet states in memory.
+    If `limit` is provided, only that many most recently stored states are kept.
     """
 
-    def __init__(self):
+    def __init__(self, limit: Optional[int] = None):
         super().__init__()
         self.__storage = {}
+        self.__limit = limit
 
     def _storage_write(self, number: int, state: StarknetState):
         self.__storage[number] = state.copy()
 
+        if self.__limit is not None and len(self.__storage) > self.__limit:
+            oldest_number = next(iter(self.__storage))
+            del self.__storage[oldest_number]
+
     def _storage_remove(self, number: int):
-        del self.__storage[number]
+        # the state might have already been evicted
+        self.__storage.pop(number, None)
 
     def _storage_read(self, number: int) -> StarknetState:
         return self.__storage[number]
@@ -2,7 +2,7 @@
 Classes for storing and handling transactions.
 """
 
-from typing import Dict, List, Optional
+from typing import Dict, List, Optional, Set
 
 from services.everest.business_logic.transaction_execution_objects import (
     TransactionFailureReason,
@@ -76,7 +76,7 @@ class DevnetTransaction:
         ):
             self.execution_resources = execution_info.call_info.execution_resources
 
-    def __get_actual_fee(self) -> int:
+    def get_actual_fee(self) -> int:
         """Returns the actual fee"""
         return (
             self.execution_info.actual_fee
@@ -98,12 +98,11 @@ class DevnetTransaction:
         if not hasattr(self.execution_info.call_info, "l2_to_l1_messages"):
             return l2_to_l1_messages
 
-        contract_address = self.execution_info.call_info.contract_address
-
         for l2_to_l1_message in self.execution_info.get_sorted_l2_to_l1_messages():
             l2_to_l1_messages.append(
                 L2ToL1Message(
-                    from_address=contract_address,
+                    # the sending contract, not the account which initiated the tx
+                    from_address=l2_to_l1_message.from_address,
                     to_address=Web3.to_checksum_address(
                         hex(l2_to_l1_message.to_address)
                     ),
@@ -113,6 +112,25 @@ class DevnetTransaction:
 
         return l2_to_l1_messages
 
+    def get_block_number(self) -> Optional[int]:
+        """Returns the number of the block the transaction is included in"""
+        return self.__block_number
+
+    def get_visited_contracts(self) -> Set[int]:
+        """Returns the addresses of the sender and of all contracts called during execution"""
+        visited = set()
+        for prop in ["sender_address", "contract_address"]:
+            if hasattr(self.internal_tx, prop):
+                visited.add(getattr(self.internal_tx, prop))
+
+        calls = list(getattr(self.execution_info, "non_optional_calls", []))
+        while calls:
+            call_info = calls.pop()
+            visited.add(call_info.contract_address)
+            calls.extend(call_info.internal_calls)
+
+        return visited
+
     def __get_block_hash(self) -> int:
         """Returns the block hash"""
         return self.block.block_hash if self.block else None
@@ -121,6 +139,11 @@ class DevnetTransaction:
         """Sets the block hash and number of the transaction"""
         self.block = block
 
+    def accept_on_l1(self):
+        """Marks the transaction as accepted on L1"""
+        self.status = TransactionStatus.ACCEPTED_ON_L1
+        self.finality_status = FinalityStatus.ACCEPTED_ON_L1
+
     def set_failure_reason(self, error_message: str):
         """Sets the failure reason of the transaction"""
         self.transaction_failure_reason = TransactionFailureReason(
@@ -153,7 +176,7 @@ class DevnetTransaction:
         return TransactionReceipt.from_tx_info(
             transaction_hash=self.transaction_hash,
             tx_info=tx_info,
-            actual_fee=self.__get_actual_fee(),
+            actual_fee=self.get_actual_fee(),
             events=self.__get_events(),
             execution_resources=self.execution_resources,
             l2_to_l1_messages=self.__get_l2_to_l1_messages(),
@@ -191,7 +214,7 @@ class DevnetTransaction:
             execution_status=self.execution_status,
             transaction_hash=self.internal_tx.hash_value,
             transaction_index=self.transaction_index,
-            actual_fee=self.__get_actual_fee(),
+            actual_fee=self.get_actual_fee(),
             events=self.__get_events(),
             execution_resources=self.execution_resources,
             l2_to_l1_messages=self.__get_l2_to_l1_messages(),
@@ -200,6 +223,19 @@ class DevnetTransaction:
         )
 
 
+def _is_in_block_range(
+    transaction: DevnetTransaction,
+    from_block: Optional[int],
+    to_block: Optional[int],
+) -> bool:
+    block_number = transaction.get_block_number()
+    if from_block is not None and block_number < from_block:
+        return False
+    if to_block is not None and block_number > to_block:
+        return False
+    return True
+
+
 class DevnetTransactions:
     """
     This class is used to store transactions.
@@ -230,6 +266,111 @@ class DevnetTransactions:
         """
         return len(self.__instances)
 
+    def contains(self, tx_hash: int) -> bool:
+        """
+        Check if a transaction with the given hash is stored locally.
+        """
+        return tx_hash in self.__instances
+
+    def get_transaction_hashes_by_contract(
+        self,
+        contract_address: int,
+        from_block: Optional[int] = None,
+        to_block: Optional[int] = None,
+    ) -> List[int]:
+        """
+        Get hashes of executed transactions which touched the contract, in the order of execution.
+        Optionally limited to the inclusive block range.
+        """
+        tx_hashes = []
+        for tx_hash, transaction in self.__instances.items():
+            if transaction.status in [
+                TransactionStatus.REJECTED,
+                TransactionStatus.REVERTED,
+            ]:
+                continue
+
+            if not _is_in_block_range(transaction, from_block, to_block):
+                continue
+
+            if contract_address in transaction.get_visited_contracts():
+                tx_hashes.append(tx_hash)
+
+        return tx_hashes
+
+    def get_fees_paid(
+        self,
+        sender_address: int,
+        from_block: Optional[int] = None,
+        to_block: Optional[int] = None,
+    ) -> int:
+        """
+        Get the sum of actual fees of executed transactions sent from `sender_address`.
+        Optionally limited to the inclusive block range.
+        """
+        fees_paid = 0
+        for transaction in self.__instances.values():
+            if transaction.status in [
+                TransactionStatus.REJECTED,
+                TransactionStatus.REVERTED,
+            ]:
+                continue
+
+            if not _is_in_block_range(transaction, from_block, to_block):
+                continue
+
+            tx_sender_address = getattr(transaction.internal_tx, "sender_address", None)
+            if tx_sender_address == sender_address:
+                fees_paid += transaction.get_actual_fee()
+
+        return fees_paid
+
+    def get_declaration_hash(self, class_hash: int) -> Optional[int]:
+        """
+        Get the hash of the executed transaction which declared the class with
+        `class_hash`, if it is stored locally.
+        """
+        for tx_hash, transaction in self.__instances.items():
+            if transaction.status in [
+                TransactionStatus.REJECTED,
+                TransactionStatus.REVERTED,
+            ]:
+                continue
+
+            internal_tx = transaction.internal_tx
+            if (
+                internal_tx.tx_type == TransactionType.DECLARE
+                and internal_tx.class_hash == class_hash
+            ):
+                return tx_hash
+
+        return None
+
+    def get_declared_class_hashes(self, to_block: Optional[int] = None) -> List[int]:
+        """
+        Get hashes of classes declared by executed transactions, in declaration order.
+        Optionally limited to classes declared up to and including `to_block`.
+        """
+        class_hashes = []
+        for transaction in self.__instances.values():
+            if transaction.status in [
+                TransactionStatus.REJECTED,
+                TransactionStatus.REVERTED,
+            ]:
+                continue
+
+            if not _is_in_block_range(transaction, None, to_block):
+                continue
+
+            internal_tx = transaction.internal_tx
+            if (
+                internal_tx.tx_type == TransactionType.DECLARE
+                and internal_tx.class_hash not in class_hashes
+            ):
+                class_hashes.append(internal_tx.class_hash)
+
+        return class_hashes
+
     def store(self, tx_hash: int, transaction: DevnetTransaction):
         """
         Store a transaction.
@@ -267,6 +408,29 @@ class DevnetTransactions:
 
         return transaction.get_trace()
 
+    def get_transaction_execution_info(self, tx_hash: str) -> TransactionExecutionInfo:
+        """
+        Get the execution info of a transaction executed by Devnet.
+        """
+        transaction = self.__get_transaction_by_hash(tx_hash)
+
+        if transaction is None:
+            raise StarknetDevnetException(
+                code=StarknetErrorCode.TRANSACTION_NOT_FOUND,
+                message=f"Transaction corresponding to hash {tx_hash} was not executed by Devnet.",
+            )
+
+        if transaction.status in [
+            TransactionStatus.REJECTED,
+            TransactionStatus.REVERTED,
+        ]:
+            raise StarknetDevnetException(
+                code=StarknetErrorCode.NO_TRACE,
+                message=f"Transaction corresponding to hash {tx_hash} has no execution info; status: {transaction.status.name}.",
+            )
+
+        return transaction.execution_info
+
     async def get_transaction_receipt(self, tx_hash: str):
         """
         Get a transaction receipt.
@@ -302,7 +466,7 @@ class DevnetTransactions:
             assert transaction.block is not None
             status_response["block_hash"] = hex(transaction.block.block_hash)
 
-        if transaction.status == ExecutionStatus.REJECTED:
+        if transaction.status == TransactionStatus.REJECTED:
             status_response["tx_failure_reason"] = tx_info.transaction_failure_reason
 
         elif tx_info.execution_status is ExecutionStatus.REVERTED:
@@ -21,6 +21,7 @@ from starkware.starknet.services.api.feeder_gateway.response_objects import (
     ClassHashPair,
     ContractAddressHashPair,
     FeeEstimationInfo,
+    StateDiff,
     StorageEntry,
 )
 from starkware.starknet.testing.contract import StarknetContract
@@ -194,6 +195,32 @@ async def get_replaced_classes(
     return replaced
 
 
+def _get_cached_storage_entries(state: CachedState) -> Dict[Tuple[int, int], int]:
+    """
+    Returns the storage entries known to `state`, i.e. written or read by Devnet.
+    When forking, storage of the origin which was never accessed is not included.
+    """
+    # CachedState doesn't expose its cache, but it is the only place that
+    # knows which storage entries exist
+    # pylint: disable=protected-access
+    return {
+        **state.cache._storage_initial_values,
+        **state.cache._storage_writes,
+    }
+
+
+def get_non_zero_storage(state: CachedState, address: int) -> Dict[int, int]:
+    """
+    Returns the non-zero storage of the contract at `address` known to `state`,
+    i.e. the storage written or read by Devnet
+    """
+    storage: Dict[int, int] = {}
+    for (entry_address, key), value in _get_cached_storage_entries(state).items():
+        if entry_address == address and value:
+            storage[key] = value
+    return storage
+
+
 async def get_storage_diffs(
     previous_state: CachedState,
     current_state: CachedState,
@@ -226,6 +253,49 @@ async def get_storage_diffs(
     return storage_diffs
 
 
+async def get_nonce_diffs(
+    previous_state: CachedState, current_state: CachedState
+) -> Dict[int, int]:
+    """Returns nonces modified from change"""
+    nonce_diffs: Dict[int, int] = {}
+    for address, nonce in current_state.cache.address_to_nonce.items():
+        previous_nonce = await previous_state.get_nonce_at(
+            StorageDomain.ON_CHAIN, address
+        )
+        if previous_nonce != nonce:
+            nonce_diffs[address] = nonce
+    return nonce_diffs
+
+
+def merge_state_diffs(older: StateDiff, newer: StateDiff) -> StateDiff:
+    """Merge two consecutive state diffs into one, with `newer` taking precedence"""
+    storage_diffs: Dict[int, Dict[int, StorageEntry]] = {}
+    for state_diff in (older, newer):
+        for address, entries in state_diff.storage_diffs.items():
+            storage = storage_diffs.setdefault(address, {})
+            for entry in entries:
+                storage[entry.key] = entry
+
+    replaced_classes = {pair.address: pair for pair in older.replaced_classes}
+    replaced_classes.update({pair.address: pair for pair in newer.replaced_classes})
+
+    return StateDiff(
+        deployed_contracts=[*older.deployed_contracts, *newer.deployed_contracts],
+        old_declared_contracts=tuple(
+            dict.fromkeys(
+                [*older.old_declared_contracts, *newer.old_declared_contracts]
+            )
+        ),
+        declared_classes=[*older.declared_classes, *newer.declared_classes],
+        replaced_classes=list(replaced_classes.values()),
+        storage_diffs={
+            address: list(storage.values())
+            for address, storage in storage_diffs.items()
+        },
+        nonces={**older.nonces, **newer.nonces},
+    )
+
+
 async def assert_not_declared(class_hash: int, compiled_class_hash: int):
     """Assert class is not declared"""
     if compiled_class_hash != 0:
@@ -286,6 +356,21 @@ suppress_feeder_gateway_client_logger = LogSuppressor("services.external_api.cli
 logger = logging.getLogger("gunicorn.error")
 
 
+class JsonLogFormatter(logging.Formatter):
+    """Formats log records as single-line JSON objects"""
+
+    def format(self, record: logging.LogRecord) -> str:
+        formatted = {
+            "timestamp": self.formatTime(record),
+            "level": record.levelname,
+            "logger": record.name,
+            "message": record.getMessage(),
+        }
+        if record.exc_info:
+            formatted["exception"] = self.formatException(record.exc_info)
+        return json.dumps(formatted)
+
+
 def extract_transaction_info_to_log(transaction: dict) -> dict:
     """Getting info about transaction for logging"""
     keys_to_exclude = [
@@ -283,7 +283,9 @@ def test_estimate_fee_with_invalid_call_data():
         "starknet_estimateFee", {"request": [invoke_transaction], "block_id": "latest"}
     )
 
-    assert ex["error"] == {"code": 40, "message": "Contract error"}
+    assert ex["error"]["code"] == 40
+    assert ex["error"]["message"] == "Contract error"
+    assert "While handling calldata" in ex["error"]["data"]["revert_error"]
 
 
 @pytest.mark.usefixtures("run_devnet_in_background")
@@ -330,4 +332,6 @@ def test_estimate_fee_with_invalid_message_selector():
         "starknet_estimateFee", {"request": [txn], "block_id": "latest"}
     )
 
-    assert ex["error"] == {"code": 40, "message": "Contract error"}
+    assert ex["error"]["code"] == 40
+    assert ex["error"]["message"] == "Contract error"
+    assert "Entry point" in ex["error"]["data"]["revert_error"]
@@ -10,7 +10,11 @@ from test.account import (
     invoke,
     send_declare_v2,
 )
-from test.rpc.rpc_utils import deploy_and_invoke_storage_contract, rpc_call
+from test.rpc.rpc_utils import (
+    BackgroundDevnetClient,
+    deploy_and_invoke_storage_contract,
+    rpc_call,
+)
 from test.rpc.test_data.get_events import GET_EVENTS_TEST_DATA, create_get_events_filter
 from test.shared import (
     CONTRACT_PATH,
@@ -32,6 +36,8 @@ from test.util import (
     devnet_in_background,
 )
 
+import json
+
 import pytest
 from starkware.starknet.business_logic.transaction.objects import compute_class_hash
 from starkware.starknet.public.abi import get_storage_var_address
@@ -187,6 +193,85 @@ def test_chain_id(params):
     assert rpc_chain_id == hex(chain_id)
 
 
+@pytest.mark.parametrize("message_id", ["some-id", 42, 4.2, None])
+@pytest.mark.usefixtures("run_devnet_in_background")
+def test_message_id_is_echoed_unchanged(message_id):
+    """The id of the request should be returned with its original type"""
+    resp = BackgroundDevnetClient.post(
+        "/rpc",
+        {"jsonrpc": "2.0", "method": "starknet_chainId", "id": message_id},
+    ).json()
+
+    assert resp["id"] == message_id
+    assert type(resp["id"]) is type(message_id)  # pylint: disable=unidiomatic-typecheck
+    assert "result" in resp, f"Unexpected response: {resp}"
+
+
+@pytest.mark.parametrize("message_id", ["some-id", 42, None])
+@pytest.mark.usefixtures("run_devnet_in_background")
+def test_message_id_is_echoed_in_error(message_id):
+    """The id of the request should also be returned in error responses"""
+    resp = BackgroundDevnetClient.post(
+        "/rpc",
+        {"jsonrpc": "2.0", "method": "starknet_nonExistent", "id": message_id},
+    ).json()
+
+    assert resp["id"] == message_id
+    assert resp["error"]["code"] == PredefinedRpcErrorCode.METHOD_NOT_FOUND.value
+
+
+@pytest.mark.usefixtures("run_devnet_in_background")
+def test_request_without_method():
+    """Requests without a method should be rejected, keeping the id"""
+    resp = BackgroundDevnetClient.post("/rpc", {"jsonrpc": "2.0", "id": 1}).json()
+
+    assert resp["id"] == 1
+    assert resp["error"]["code"] == PredefinedRpcErrorCode.INVALID_REQUEST.value
+
+
+@pytest.mark.parametrize("message_id", [{"a": 1}, [1], True])
+@pytest.mark.usefixtures("run_devnet_in_background")
+def test_request_with_illegal_message_id(message_id):
+    """Requests with an id which is not a string, a number or null should be rejected"""
+    resp = BackgroundDevnetClient.post(
+        "/rpc",
+        {"jsonrpc": "2.0", "method": "starknet_chainId", "id": message_id},
+    ).json()
+
+    assert resp["id"] is None
+    assert resp["error"]["code"] == PredefinedRpcErrorCode.INVALID_REQUEST.value
+
+
+@pytest.mark.usefixtures("run_devnet_in_background")
+def test_supported_methods():
+    """Test listing of supported methods"""
+    resp = BackgroundDevnetClient.get("/rpc/methods")
+    assert resp.status_code == 200
+
+    supported_methods = resp.json()["methods"]
+    for method in [
+        "starknet_chainId",
+        "starknet_getEvents",
+        "starknet_addInvokeTransaction",
+        "starknet_simulateTransaction",
+    ]:
+        assert method in supported_methods
+    assert "starknet_nonExistent" not in supported_methods
+
+
+@pytest.mark.usefixtures("run_devnet_in_background")
+def test_openrpc_spec():
+    """Test that the served spec describes exactly the supported methods"""
+    resp = BackgroundDevnetClient.get("/rpc/spec")
+    assert resp.status_code == 200
+    spec = resp.json()
+
+    supported_methods = BackgroundDevnetClient.get("/rpc/methods").json()["methods"]
+    assert [method["name"] for method in spec["methods"]] == supported_methods
+    assert "schemas" in spec["components"]
+    assert "starknet_api_openrpc.json#" not in json.dumps(spec)
+
+
 @pytest.mark.parametrize("params", [{}, None])
 @pytest.mark.usefixtures("run_devnet_in_background")
 def test_syncing(params):
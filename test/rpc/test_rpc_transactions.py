@@ -884,6 +884,8 @@ def test_add_deploy_account_transaction(deploy_account_details):
     )
     tx_after = tx_after["result"]
     assert_tx_status(tx_after["transaction_hash"], "ACCEPTED_ON_L2")
+    assert tx_after["contract_address"] == rpc_felt(address)
+    assert tx_after["class_hash"] == rpc_felt(deploy_account_tx.class_hash)
 
     # assert that contract can be interacted with
     retrieved_public_key = call(
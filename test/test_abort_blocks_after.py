@@ -5,6 +5,7 @@ from test.rpc.rpc_utils import rpc_call
 
 import requests
 from starkware.starknet.definitions.error_codes import StarknetErrorCode
+from starkware.starkware_utils.error_handling import StarkErrorCode
 
 from starknet_devnet.blueprints.rpc.utils import rpc_felt
 
@@ -22,9 +23,11 @@ from .util import (
     assert_transaction,
     assert_tx_status,
     call,
+    create_empty_block,
     demand_block_creation,
     devnet_in_background,
     get_block,
+    mint,
 )
 
 NON_EXISTENT_BLOCK = "0x9"
@@ -37,6 +40,11 @@ def abort_blocks(block_hash):
     )
 
 
+def abort_latest_blocks(count):
+    """Abort the `count` latest blocks"""
+    return requests.post(f"{APP_URL}/abort_blocks", json={"count": count})
+
+
 @devnet_in_background()
 def test_abort_not_existing_block():
     """Test abort of not existing block."""
@@ -72,6 +80,12 @@ def test_abort_single_block_single_transaction():
     assert contract_deploy_block_after_abort["status"] == "ABORTED"
     assert_transaction(contract_deploy_info["tx_hash"], "REVERTED")
 
+    # Aborted block should retain its transactions
+    assert (
+        contract_deploy_block_after_abort["transactions"]
+        == contract_deploy_block["transactions"]
+    )
+
     # Test RPC get block status mapping from ABORTED to REVERTED
     rpc_aborted_block = rpc_call(
         "starknet_getBlockWithTxs",
@@ -312,3 +326,80 @@ def test_pending_state_with_abort_block():
     response = abort_blocks(latest_block["block_hash"])
     assert response.status_code == 200
     assert_tx_status(invoke_tx_hash, "REVERTED")
+
+
+@devnet_in_background("--state-archive-limit", "1")
+def test_abort_blocks_with_discarded_state():
+    """Test that blocks are not aborted if the state to revert to was discarded"""
+    declare_and_deploy_with_chargeable(contract=CONTRACT_PATH, inputs=[0])
+    latest_block = get_block(block_number="latest")
+
+    # the state of the parent of the latest block has been discarded
+    response = abort_blocks(latest_block["block_hash"])
+    assert response.status_code == 400
+    assert response.json()["code"] == str(StarknetErrorCode.OUT_OF_RANGE_BLOCK_ID)
+    assert get_block(block_number="latest") == latest_block
+
+
+@devnet_in_background()
+def test_abort_latest_blocks_by_count():
+    """Test abort of the latest blocks specified by their count"""
+    genesis_block = get_block(block_number="latest")
+    created_blocks = [create_empty_block() for _ in range(3)]
+
+    response = abort_latest_blocks(2)
+    assert response.status_code == 200, response.json()
+    assert response.json()["aborted"] == [
+        created_blocks[2]["block_hash"],
+        created_blocks[1]["block_hash"],
+    ]
+
+    latest_block = get_block(block_number="latest")
+    assert latest_block["block_hash"] == created_blocks[0]["block_hash"]
+    assert latest_block["block_number"] == genesis_block["block_number"] + 1
+
+
+@devnet_in_background()
+def test_abort_latest_blocks_reaching_genesis():
+    """Test abort by count that would include the genesis block should fail"""
+    create_empty_block()
+    latest_block = get_block(block_number="latest")
+
+    response = abort_latest_blocks(2)
+    assert response.status_code == 400
+    assert response.json()["code"] == str(StarknetErrorCode.OUT_OF_RANGE_BLOCK_ID)
+    assert get_block(block_number="latest") == latest_block
+
+
+@devnet_in_background("--blocks-on-demand", "--state-archive-limit", "1")
+def test_abort_latest_blocks_with_discarded_state_keeps_pending():
+    """Test that the pending block is not generated if the abort by count fails"""
+    create_empty_block()
+    create_empty_block()
+    latest_block = get_block(block_number="latest")
+    mint_tx_hash = mint(PREDEPLOYED_ACCOUNT_ADDRESS, amount=10)["tx_hash"]
+
+    # the state of the parent of the latest block has been discarded
+    response = abort_latest_blocks(2)
+    assert response.status_code == 400
+    assert response.json()["code"] == str(StarknetErrorCode.OUT_OF_RANGE_BLOCK_ID)
+    assert get_block(block_number="latest") == latest_block
+
+    # the pending transaction is still pending
+    new_block = create_empty_block()
+    assert [tx["transaction_hash"] for tx in new_block["transactions"]] == [
+        mint_tx_hash
+    ]
+
+
+@devnet_in_background()
+def test_abort_blocks_with_both_hash_and_count():
+    """Test abort specified both by starting block hash and count should fail"""
+    latest_block = create_empty_block()
+
+    response = requests.post(
+        f"{APP_URL}/abort_blocks",
+        json={"startingBlockHash": latest_block["block_hash"], "count": 1},
+    )
+    assert response.status_code == 400
+    assert response.json()["code"] == str(StarkErrorCode.MALFORMED_REQUEST)
@@ -6,6 +6,11 @@ import pytest
 import requests
 from starkware.crypto.signature.signature import private_to_stark_key
 from starkware.starknet.definitions.error_codes import StarknetErrorCode
+from starkware.starknet.definitions.transaction_type import TransactionType
+from starkware.starknet.services.api.gateway.transaction import InvokeFunction
+
+from starknet_devnet.account_util import get_execute_args
+from starknet_devnet.general_config import DEFAULT_GENERAL_CONFIG
 
 from .account import (
     ACCOUNT_ABI_PATH,
@@ -16,6 +21,7 @@ from .account import (
     get_nonce,
     invoke,
 )
+from .rpc.rpc_utils import rpc_call
 from .settings import APP_URL
 from .shared import (
     ABI_PATH,
@@ -24,6 +30,7 @@ from .shared import (
     PREDEPLOY_ACCOUNT_CLI_ARGS,
     PREDEPLOYED_ACCOUNT_ADDRESS,
     PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+    SUPPORTED_TX_VERSION,
 )
 from .util import (
     ErrorExpector,
@@ -34,6 +41,7 @@ from .util import (
     assert_tx_status,
     call,
     devnet_in_background,
+    get_block,
     get_transaction_receipt,
     mint,
 )
@@ -77,9 +85,12 @@ def deploy_events_contract():
     )
 
 
-def get_account_balance(address: str, server_url=APP_URL) -> int:
+def get_account_balance(address: str, server_url=APP_URL, block_number=None) -> int:
     """Get balance (wei) of account with `address` (hex)."""
-    resp = requests.get(f"{server_url}/account_balance?address={address}")
+    params = {"address": address}
+    if block_number is not None:
+        params["blockNumber"] = block_number
+    resp = requests.get(f"{server_url}/account_balance", params=params)
     assert resp.status_code == 200, resp.json()
     return int(resp.json()["amount"])
 
@@ -293,6 +304,50 @@ def test_insufficient_balance_and_insufficient_fee():
     assert_equal(final_account_balance, initial_account_balance)
 
 
+@pytest.mark.account
+@devnet_in_background(
+    *PREDEPLOY_ACCOUNT_CLI_ARGS, "--initial-balance", "10", "--store-rejected"
+)
+def test_store_rejected():
+    """Test storing a transaction which failed validation"""
+    deploy_info = deploy_empty_contract()
+    account_address = PREDEPLOYED_ACCOUNT_ADDRESS
+
+    calls = [(deploy_info["address"], "increase_balance", [10, 20])]
+    max_fee = get_account_balance(account_address) + 1
+    nonce = get_nonce(account_address)
+    signature, execute_calldata = get_execute_args(
+        calls=calls,
+        account_address=account_address,
+        private_key=PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+        nonce=nonce,
+        version=SUPPORTED_TX_VERSION,
+        max_fee=max_fee,
+    )
+    invoke_tx = InvokeFunction(
+        version=SUPPORTED_TX_VERSION,
+        sender_address=int(account_address, 16),
+        calldata=execute_calldata,
+        max_fee=max_fee,
+        nonce=nonce,
+        signature=[int(s) for s in signature],
+    )
+    tx_hash = hex(invoke_tx.calculate_hash(DEFAULT_GENERAL_CONFIG))
+    latest_block = get_block(block_number="latest")
+
+    resp = requests.post(
+        f"{APP_URL}/gateway/add_transaction",
+        json={**invoke_tx.dump(), "type": TransactionType.INVOKE_FUNCTION.name},
+    )
+    assert resp.json()["code"] == str(StarknetErrorCode.INSUFFICIENT_ACCOUNT_BALANCE)
+
+    assert_tx_status(tx_hash, "REJECTED")
+    assert get_transaction_receipt(tx_hash).get("block_hash") is None
+
+    # no block was generated for the rejected transaction
+    assert get_block(block_number="latest") == latest_block
+
+
 @pytest.mark.account
 @devnet_in_background()
 def test_multicall():
@@ -388,6 +443,31 @@ def test_get_nonce_endpoint():
     assert final_resp.json() == "0x3"  # invoke
 
 
+@pytest.mark.account
+@devnet_in_background(*PREDEPLOY_ACCOUNT_CLI_ARGS, "--account-initial-nonce", "5")
+def test_account_initial_nonce():
+    """Test that predeployed accounts start with the configured nonce"""
+    account_address = PREDEPLOYED_ACCOUNT_ADDRESS
+
+    assert get_nonce(account_address, block_number=0) == 5
+    assert get_nonce(account_address, block_number="latest") == 5
+    rpc_nonce = rpc_call(
+        "starknet_getNonce",
+        params={"block_id": {"block_number": 0}, "contract_address": account_address},
+    )["result"]
+    assert int(rpc_nonce, 16) == 5
+
+    # the configured nonce is used by subsequent transactions
+    declare_and_deploy(
+        contract=CONTRACT_PATH,
+        account_address=account_address,
+        private_key=PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+        inputs=[0],
+    )
+    assert get_nonce(account_address, block_number="latest") == 7
+    assert get_nonce(account_address, block_number=0) == 5
+
+
 @devnet_in_background(*PREDEPLOY_ACCOUNT_CLI_ARGS)
 def test_failed_validation():
     """Test invalid nonce in transaction"""
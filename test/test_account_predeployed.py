@@ -1,5 +1,7 @@
 """Predeployed account tests"""
 
+import subprocess
+
 import pytest
 import requests
 from starkware.starknet.core.os.contract_class.deprecated_class_hash import (
@@ -14,7 +16,17 @@ from starknet_devnet.contract_class_wrapper import (
 
 from .settings import APP_URL
 from .support.assertions import assert_valid_schema
-from .util import assert_equal, devnet_in_background, load_contract_class
+from .test_account import get_account_balance
+from .util import (
+    DevnetBackgroundProc,
+    assert_equal,
+    assert_hex_equal,
+    devnet_in_background,
+    get_class_hash_at,
+    load_contract_class,
+    mint,
+    read_stream,
+)
 
 ACCOUNTS_SEED_DEVNET_ARGS = [
     "--accounts",
@@ -46,6 +58,60 @@ def test_predeployed_accounts_predefined_values():
     assert_valid_schema(response.json(), "predeployed_accounts_fixed_seed.json")
 
 
+@pytest.mark.account_predeployed
+@devnet_in_background("--accounts", "10")
+def test_predeployed_accounts_pagination():
+    """Test slicing of predeployed accounts with skip and limit"""
+    all_accounts = requests.get(f"{APP_URL}/predeployed_accounts").json()
+    assert len(all_accounts) == 10
+
+    def get_page(**params):
+        response = requests.get(f"{APP_URL}/predeployed_accounts", params=params)
+        assert response.status_code == 200
+        page = response.json()
+        assert page["total"] == 10
+        return page["accounts"]
+
+    assert get_page(skip=3, limit=4) == all_accounts[3:7]
+    assert get_page(skip=8, limit=5) == all_accounts[8:]
+    assert get_page(skip=10) == []
+    assert get_page(limit=2) == all_accounts[:2]
+    assert get_page(limit=0) == []
+
+    response = requests.get(f"{APP_URL}/predeployed_accounts", params={"limit": -1})
+    assert response.status_code == 400
+
+
+@pytest.mark.account_predeployed
+@devnet_in_background(*ACCOUNTS_SEED_DEVNET_ARGS)
+def test_predeployed_accounts_min_balance():
+    """Test filtering of predeployed accounts by their current balance"""
+    all_accounts = requests.get(f"{APP_URL}/predeployed_accounts").json()
+    mint(address=all_accounts[1]["address"], amount=10)
+
+    def get_accounts(**params):
+        response = requests.get(f"{APP_URL}/predeployed_accounts", params=params)
+        assert response.status_code == 200
+        return response.json()
+
+    accounts = get_accounts(minBalance=0)
+    assert [account["address"] for account in accounts] == [
+        account["address"] for account in all_accounts
+    ]
+    assert [account["balance"] for account in accounts] == [1000, 1010, 1000]
+
+    accounts = get_accounts(minBalance=1001)
+    assert accounts == [{**all_accounts[1], "balance": 1010}]
+
+    page = get_accounts(minBalance=1001, skip=0, limit=2)
+    assert page == {"accounts": accounts, "total": 1}
+
+    response = requests.get(
+        f"{APP_URL}/predeployed_accounts", params={"minBalance": "-1"}
+    )
+    assert response.status_code == 400
+
+
 @pytest.mark.account_predeployed
 def test_predeployed_chageable_account():
     """Test if chargeable account address unchanged"""
@@ -53,3 +119,48 @@ def test_predeployed_chageable_account():
         ChargeableAccount.ADDRESS,
         0x1CAF2DF5ED5DDE1AE3FAEF4ACD72522AC3CB16E23F6DC4C7F9FAED67124C511,
     )
+
+
+CUSTOM_ACCOUNT_ADDRESSES = ["0x1234", "0x5678", "0x9abc"]
+
+
+@pytest.mark.account_predeployed
+@devnet_in_background(
+    *ACCOUNTS_SEED_DEVNET_ARGS,
+    *[
+        arg
+        for address in CUSTOM_ACCOUNT_ADDRESSES
+        for arg in ["--predeployed-account-address", address]
+    ],
+)
+def test_predeployed_accounts_at_custom_addresses():
+    """Test if accounts are predeployed at the specified addresses"""
+    response = requests.get(f"{APP_URL}/predeployed_accounts")
+    assert response.status_code == 200
+    accounts = response.json()
+
+    assert [account["address"] for account in accounts] == CUSTOM_ACCOUNT_ADDRESSES
+    # keys are still derived from the seed
+    assert accounts[0]["private_key"] == "0xc4da537c1651ddae44867db30d67b366"
+
+    for address in CUSTOM_ACCOUNT_ADDRESSES:
+        assert_hex_equal(get_class_hash_at(address), hex(DEFAULT_ACCOUNT_HASH))
+        assert_equal(get_account_balance(address), 1000)
+
+
+@pytest.mark.account_predeployed
+def test_predeployed_account_addresses_not_matching_accounts():
+    """Test if devnet fails to start if the number of addresses is wrong"""
+    proc = DevnetBackgroundProc().start(
+        "--accounts",
+        "2",
+        "--predeployed-account-address",
+        "0x1234",
+        stderr=subprocess.PIPE,
+        stdout=subprocess.PIPE,
+    )
+    assert (
+        "Error: 1 --predeployed-account-address provided, but --accounts is 2"
+        in read_stream(proc.stderr)
+    )
+    assert proc.returncode == 1
@@ -0,0 +1,61 @@
+"""Test the history of Devnet-specific actions"""
+
+import requests
+
+from .settings import APP_URL
+from .shared import PREDEPLOYED_ACCOUNT_ADDRESS
+from .util import devnet_in_background, increase_time, mint, set_time
+
+
+def get_action_history():
+    """Get the recorded actions"""
+    resp = requests.get(f"{APP_URL}/action_history")
+    assert resp.status_code == 200, resp.json()
+    return resp.json()["actions"]
+
+
+@devnet_in_background()
+def test_actions_recorded_in_order():
+    """Successful actions should be recorded in the order of execution"""
+    assert get_action_history() == []
+
+    set_time(1_700_000_000)
+    increase_time(100)
+    mint(PREDEPLOYED_ACCOUNT_ADDRESS, 10, lite=True)
+
+    # neither failed actions nor queries are recorded
+    assert increase_time(-1).status_code == 400
+    requests.get(f"{APP_URL}/config")
+
+    actions = get_action_history()
+    assert [(action["endpoint"], action["params"]) for action in actions] == [
+        ("/set_time", {"time": 1_700_000_000}),
+        ("/increase_time", {"time": 100}),
+        (
+            "/mint",
+            {"address": PREDEPLOYED_ACCOUNT_ADDRESS, "amount": 10, "lite": True},
+        ),
+    ]
+
+    timestamps = [action["timestamp"] for action in actions]
+    assert timestamps == sorted(timestamps)
+
+
+@devnet_in_background("--action-history-size", "2")
+def test_action_history_size():
+    """Only the configured number of the most recent actions should be kept"""
+    for time_s in [1, 2, 3]:
+        increase_time(time_s)
+
+    actions = get_action_history()
+    assert [action["params"] for action in actions] == [{"time": 2}, {"time": 3}]
+
+
+@devnet_in_background()
+def test_action_history_cleared_on_restart():
+    """Restarting should only keep the restart itself in the history"""
+    increase_time(100)
+    requests.post(f"{APP_URL}/restart")
+
+    actions = get_action_history()
+    assert [action["endpoint"] for action in actions] == ["/restart"]
@@ -2,7 +2,11 @@
 Test block number
 """
 
+import subprocess
+
 import pytest
+import requests
+from starkware.starknet.definitions.error_codes import StarknetErrorCode
 
 from .account import declare, declare_and_deploy_with_chargeable, invoke
 from .shared import (
@@ -13,10 +17,19 @@ from .shared import (
     PREDEPLOYED_ACCOUNT_ADDRESS,
     PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
 )
-from .util import call, devnet_in_background
+from .settings import APP_URL
+from .test_restart import restart
+from .util import (
+    DevnetBackgroundProc,
+    call,
+    devnet_in_background,
+    get_block,
+    read_stream,
+)
 
 BLOCK_NUMBER_CONTRACT_PATH = f"{ARTIFACTS_PATH}/block_number.cairo/block_number.json"
 BLOCK_NUMBER_ABI_PATH = f"{ARTIFACTS_PATH}/block_number.cairo/block_number_abi.json"
+STARTING_BLOCK_NUMBER = 1000
 
 
 def my_get_block_number(address: str):
@@ -132,3 +145,92 @@ def test_block_number_not_incremented_if_invoke_fails():
 
     block_number_after = my_get_block_number(deploy_info["address"])
     assert int(block_number_after) == GENESIS_BLOCK_NUMBER + 2
+
+
+def set_block_number(block_number: int):
+    """Send a request to set the block number of the next transaction"""
+    return requests.post(
+        f"{APP_URL}/set_block_number", json={"blockNumber": block_number}
+    )
+
+
+@devnet_in_background(*PREDEPLOY_ACCOUNT_CLI_ARGS)
+def test_set_block_number():
+    """Transactions should be executed with the set block number"""
+
+    deploy_info = declare_and_deploy_with_chargeable(BLOCK_NUMBER_CONTRACT_PATH)
+
+    response = set_block_number(100)
+    assert response.status_code == 200
+    assert response.json() == {"next_block_number": 100}
+
+    invoke(
+        calls=[(deploy_info["address"], "write_block_number", [])],
+        account_address=PREDEPLOYED_ACCOUNT_ADDRESS,
+        private_key=PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+    )
+
+    written_block_number = call(
+        function="read_block_number",
+        inputs=[],
+        address=deploy_info["address"],
+        abi_path=BLOCK_NUMBER_ABI_PATH,
+    )
+    assert int(written_block_number) == 100
+    assert int(my_get_block_number(deploy_info["address"])) == 100
+
+    # no intermediate blocks are created
+    # genesis + declare + deploy + invoke
+    assert get_block()["block_number"] == GENESIS_BLOCK_NUMBER + 3
+
+
+@devnet_in_background(*PREDEPLOY_ACCOUNT_CLI_ARGS)
+def test_set_block_number_cannot_decrease():
+    """Block number should not be settable below the number of the next block"""
+
+    declare_and_deploy_with_chargeable(BLOCK_NUMBER_CONTRACT_PATH)
+
+    # genesis + declare + deploy
+    response = set_block_number(GENESIS_BLOCK_NUMBER + 2)
+    assert response.status_code == 400
+    assert "Block number can only be increased" in response.json()["message"]
+
+    response = set_block_number(GENESIS_BLOCK_NUMBER + 3)
+    assert response.status_code == 200
+
+
+@devnet_in_background("--starting-block-number", str(STARTING_BLOCK_NUMBER))
+def test_starting_block_number():
+    """Blocks should be numbered starting from the configured number"""
+    genesis_block = get_block(block_number=STARTING_BLOCK_NUMBER)
+    assert genesis_block["block_number"] == STARTING_BLOCK_NUMBER
+    assert int(genesis_block["parent_block_hash"], 16) == 0
+
+    deploy_info = declare_and_deploy_with_chargeable(BLOCK_NUMBER_CONTRACT_PATH)
+    # genesis + declare + deploy
+    assert get_block()["block_number"] == STARTING_BLOCK_NUMBER + 2
+    assert int(my_get_block_number(deploy_info["address"])) == STARTING_BLOCK_NUMBER + 2
+
+    preceding_block = get_block(block_number=STARTING_BLOCK_NUMBER - 1)
+    assert preceding_block["code"] == str(StarknetErrorCode.BLOCK_NOT_FOUND)
+
+    # the starting block number is preserved on restart
+    restart()
+    assert get_block()["block_number"] == STARTING_BLOCK_NUMBER
+
+
+def test_starting_block_number_with_fork():
+    """Starting block number should not be configurable when forking"""
+    proc = DevnetBackgroundProc().start(
+        "--starting-block-number",
+        str(STARTING_BLOCK_NUMBER),
+        "--fork-network",
+        "alpha-goerli",
+        stderr=subprocess.PIPE,
+        stdout=subprocess.PIPE,
+    )
+    assert (
+        "Error: --starting-block-number cannot be used with --fork-network"
+        in read_stream(proc.stderr)
+    )
+    assert proc.returncode == 1
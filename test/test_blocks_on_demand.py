@@ -4,12 +4,15 @@ Test blocks on demand mode.
 
 import requests
 from starkware.starknet.definitions.error_codes import StarknetErrorCode
+from starkware.starknet.public.abi import get_storage_var_address
 from starkware.starknet.services.api.feeder_gateway.response_objects import (
     BlockStatus,
     TransactionStatus,
 )
+from starkware.starkware_utils.error_handling import StarkErrorCode
 
 from starknet_devnet.blueprints.rpc.utils import rpc_felt
+from starknet_devnet.general_config import DEFAULT_GENERAL_CONFIG
 
 from .account import declare_and_deploy_with_chargeable, get_estimated_fee, invoke
 from .rpc.rpc_utils import rpc_call
@@ -22,6 +25,7 @@ from .shared import (
     PREDEPLOY_ACCOUNT_CLI_ARGS,
     PREDEPLOYED_ACCOUNT_ADDRESS,
     PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+    STORAGE_CONTRACT_PATH,
 )
 from .test_state_update import get_state_update
 from .test_transaction_trace import get_block_traces
@@ -273,6 +277,51 @@ def test_pending_state_update():
     assert_equal(latest_state_update_before, latest_state_update)
 
 
+@devnet_in_background(*PREDEPLOY_ACCOUNT_CLI_ARGS, "--blocks-on-demand")
+def test_pending_state_update_accumulates_changes():
+    """Test that pending state update reflects all pending txs, not just the last one"""
+
+    deploy_info = declare_and_deploy_with_chargeable(STORAGE_CONTRACT_PATH)
+    contract_address = int(deploy_info["address"], 16)
+
+    value = 30
+    invoke(
+        calls=[(deploy_info["address"], "store_value", [value])],
+        account_address=PREDEPLOYED_ACCOUNT_ADDRESS,
+        private_key=PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+    )
+
+    # a later tx not touching the contract should not hide its changes
+    invoke(
+        calls=[(deploy_info["address"], "get_stored_value", [])],
+        account_address=PREDEPLOYED_ACCOUNT_ADDRESS,
+        private_key=PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+    )
+
+    state_diff = get_state_update(block_number="pending")["state_diff"]
+
+    deployed = [int(c["address"], 16) for c in state_diff["deployed_contracts"]]
+    assert deployed == [contract_address]
+
+    storage_diffs = {
+        int(address, 16): entries
+        for address, entries in state_diff["storage_diffs"].items()
+    }
+    assert storage_diffs[contract_address] == [
+        {"key": hex(get_storage_var_address("storage")), "value": hex(value)}
+    ]
+
+    nonces = {
+        int(address, 16): int(nonce, 16)
+        for address, nonce in state_diff["nonces"].items()
+    }
+    assert nonces[int(PREDEPLOYED_ACCOUNT_ADDRESS, 16)] == 2
+
+    # the created block has the same state update
+    demand_block_creation()
+    assert get_state_update(block_number="latest")["state_diff"] == state_diff
+
+
 @devnet_in_background(*PREDEPLOY_ACCOUNT_CLI_ARGS, "--blocks-on-demand")
 def test_events():
     """Test that events are stored and returned correctly in blocks-on-demand mode"""
@@ -430,3 +479,86 @@ def test_set_time_in_block_on_demand_mode():
     assert latest_block["timestamp"] == latest_block_timestamp + 10000
     assert latest_block["block_hash"] == set_time_response.json()["block_hash"]
     assert_tx_status(deploy_info["tx_hash"], "ACCEPTED_ON_L2")
+
+
+@devnet_in_background("--blocks-on-demand")
+def test_config_reports_block_generation_on_demand():
+    """Test that the config reflects block generation on demand"""
+    resp = requests.get(f"{APP_URL}/config")
+    assert resp.status_code == 200
+    assert resp.json()["block_generation_on"] == "demand"
+
+
+@devnet_in_background()
+def test_config_reports_block_generation_on_transaction():
+    """Test that the config reflects block generation on each transaction"""
+    resp = requests.get(f"{APP_URL}/config")
+    assert resp.status_code == 200
+    assert resp.json() == {
+        "block_generation_on": "transaction",
+        "lite_mode": False,
+        "instant_l1_acceptance": False,
+        "chain_id": "TESTNET",
+        "sequencer_address": hex(DEFAULT_GENERAL_CONFIG.sequencer_address),
+        "predeployed_erc20s": [],
+    }
+
+
+def _set_block_generation_mode(mode: str):
+    return requests.post(f"{APP_URL}/set_block_generation_mode", json={"mode": mode})
+
+
+@devnet_in_background()
+def test_switching_to_demand_mode():
+    """Test that transactions stay pending after switching to on-demand mode"""
+    resp = _set_block_generation_mode("demand")
+    assert resp.status_code == 200
+    assert resp.json() == {"block_generation_on": "demand", "block_hash": None}
+    assert requests.get(f"{APP_URL}/config").json()["block_generation_on"] == "demand"
+
+    latest_block_before = get_block(block_number="latest")
+    deploy_info = declare_and_deploy_with_chargeable(CONTRACT_PATH, inputs=["0"])
+    assert_equal(latest_block_before, get_block(block_number="latest"))
+    _assert_block_is_pending(get_block(block_number="pending"))
+
+    demand_block_creation()
+    latest_block = get_block(block_number="latest")
+    assert latest_block["transactions"][1]["transaction_hash"] == deploy_info["tx_hash"]
+
+
+@devnet_in_background("--blocks-on-demand")
+def test_switching_to_transaction_mode_generates_pending_block():
+    """Test that switching to transaction mode generates the pending block"""
+    deploy_info = declare_and_deploy_with_chargeable(CONTRACT_PATH, inputs=["0"])
+
+    resp = _set_block_generation_mode("transaction")
+    assert resp.status_code == 200
+    assert resp.json()["block_generation_on"] == "transaction"
+
+    latest_block = get_block(block_number="latest")
+    assert resp.json()["block_hash"] == latest_block["block_hash"]
+    assert latest_block["transactions"][1]["transaction_hash"] == deploy_info["tx_hash"]
+
+    # subsequent transactions are included in a block right away
+    next_deploy_info = declare_and_deploy_with_chargeable(CONTRACT_PATH, inputs=["0"])
+    latest_tx = get_block(block_number="latest")["transactions"][1]
+    assert latest_tx["transaction_hash"] == next_deploy_info["tx_hash"]
+
+
+@devnet_in_background()
+def test_switching_to_transaction_mode_without_pending_block():
+    """Test that no block is generated if there is nothing pending"""
+    latest_block_before = get_block(block_number="latest")
+
+    resp = _set_block_generation_mode("transaction")
+    assert resp.status_code == 200
+    assert resp.json() == {"block_generation_on": "transaction", "block_hash": None}
+    assert_equal(latest_block_before, get_block(block_number="latest"))
+
+
+@devnet_in_background()
+def test_setting_invalid_block_generation_mode():
+    """Test that unsupported modes are rejected"""
+    resp = _set_block_generation_mode("interval")
+    assert resp.status_code == 400
+    assert resp.json()["code"] == str(StarkErrorCode.MALFORMED_REQUEST)
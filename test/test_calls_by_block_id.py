@@ -257,3 +257,27 @@ def test_getting_storage_at_old_block():
         expected_value=hex(initial_balance + increment_value),
         block_number="3",
     )
+
+
+@devnet_in_background(*PREDEPLOY_ACCOUNT_CLI_ARGS, "--state-archive-limit", "2")
+def test_call_with_state_archive_limit():
+    """Expect the states of blocks older than the most recent two to be discarded"""
+    initial_value = 5
+    deploy_info = declare_and_deploy_with_chargeable(
+        CONTRACT_PATH, inputs=[str(initial_value)]
+    )
+    contract_address = deploy_info["address"]
+
+    increment_value = 7
+    _increment(contract_address, increment_value)  # block 3
+    _increment(contract_address, increment_value)  # block 4
+
+    assert _get_value(contract_address, block_number="3") == initial_value + 7
+    assert _get_value(contract_address, block_number="4") == initial_value + 14
+
+    with ErrorExpector(StarknetErrorCode.OUT_OF_RANGE_BLOCK_ID):
+        # genesis (0) + declare + deploy = block number 2
+        _get_value(contract_address, block_number="2")
+
+    # the block itself is still available
+    assert get_block(block_number=2)["block_number"] == 2
@@ -1,13 +1,18 @@
 """Testing chain id CLI params"""
 
 import subprocess
+from test.rpc.rpc_utils import rpc_call
 
 import pytest
+import requests
+from starkware.cairo.lang.cairo_constants import DEFAULT_PRIME
 from starkware.starknet.definitions.general_config import StarknetChainId
 
 from starknet_devnet.devnet_config import CHAIN_IDS
+from starknet_devnet.general_config import CustomChainId
 
 from .account import declare_and_deploy_with_chargeable, invoke
+from .settings import APP_URL
 from .shared import (
     ABI_PATH,
     CONTRACT_PATH,
@@ -21,6 +26,7 @@ from .util import (
     assert_transaction,
     assert_tx_status,
     call,
+    devnet_in_background,
     read_stream,
     terminate_and_wait,
 )
@@ -44,7 +50,7 @@ def test_chain_id_valid(chain_id):
 
 @pytest.mark.parametrize(
     "chain_id",
-    ["", "mainnet2"],
+    ["", "0xinvalid", hex(DEFAULT_PRIME), "a" * 32],
 )
 def test_chain_id_invalid(chain_id):
     """Test if the invalid chain id fails"""
@@ -55,23 +61,26 @@ def test_chain_id_invalid(chain_id):
         stdout=subprocess.PIPE,
     )
     assert (
-        f"Error: The value of --chain-id must be in {{{CHAIN_IDS}}}, got:"
+        f"Error: The value of --chain-id must be in {{{CHAIN_IDS}}},"
         in read_stream(proc.stderr)
     )
     assert proc.returncode == 1
 
 
+CUSTOM_CHAIN_ID = CustomChainId(name="SN_CUSTOM", value=0x534E5F435553544F4D)
+
+
 @pytest.mark.usefixtures("run_devnet_in_background")
 @pytest.mark.parametrize(
     "run_devnet_in_background, chain_id",
     [
         ([*PREDEPLOY_ACCOUNT_CLI_ARGS, "--chain-id", chain_id.name], chain_id)
-        for chain_id in StarknetChainId
+        for chain_id in [*StarknetChainId, CUSTOM_CHAIN_ID]
     ],
     indirect=True,
 )
 def test_deploy_and_invoke(chain_id):
-    """Test deploy and invoke with MAINNET and TESTNET chain_id"""
+    """Test deploy and invoke with known and custom chain ids"""
     deploy_info = declare_and_deploy_with_chargeable(
         CONTRACT_PATH, inputs=["0"], chain_id=chain_id
     )
@@ -92,3 +101,30 @@ def test_deploy_and_invoke(chain_id):
         function="get_balance", address=deploy_info["address"], abi_path=ABI_PATH
     )
     assert_equal(value, "30", "Invoke+call failed!")
+
+
+def _assert_chain_id(name: str, value: int):
+    resp = requests.get(f"{APP_URL}/config")
+    assert resp.json()["chain_id"] == name
+    assert rpc_call("starknet_chainId", params={})["result"] == hex(value)
+
+
+@devnet_in_background("--chain-id", CUSTOM_CHAIN_ID.name)
+def test_custom_chain_id_from_short_string():
+    """The custom chain id should be reported and preserved on restart"""
+    _assert_chain_id(CUSTOM_CHAIN_ID.name, CUSTOM_CHAIN_ID.value)
+
+    requests.post(f"{APP_URL}/restart")
+    _assert_chain_id(CUSTOM_CHAIN_ID.name, CUSTOM_CHAIN_ID.value)
+
+
+@devnet_in_background("--chain-id", "0x00abcd")
+def test_custom_chain_id_from_hex_string():
+    """A hex chain id should be reported in its normalized form"""
+    _assert_chain_id("0xabcd", 0xABCD)
+
+
+@devnet_in_background("--chain-id", "SN_MAIN")
+def test_known_chain_id_as_short_string():
+    """A short string of a known chain id should resolve to that chain id"""
+    _assert_chain_id(StarknetChainId.MAINNET.name, StarknetChainId.MAINNET.value)
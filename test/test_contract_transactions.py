@@ -0,0 +1,131 @@
+"""Test listing transactions which touched a contract and fees paid by an account"""
+
+import requests
+from starkware.starkware_utils.error_handling import StarkErrorCode
+
+from .account import declare_and_deploy_with_chargeable, invoke
+from .settings import APP_URL
+from .shared import (
+    CONTRACT_PATH,
+    PREDEPLOY_ACCOUNT_CLI_ARGS,
+    PREDEPLOYED_ACCOUNT_ADDRESS,
+    PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+)
+from .util import assert_hex_equal, devnet_in_background, get_transaction_receipt
+
+
+def get_contract_transactions(contract_address: str, **block_range):
+    """Get hashes of transactions which touched the contract"""
+    resp = requests.get(
+        f"{APP_URL}/contract_transactions",
+        params={"contractAddress": contract_address, **block_range},
+    )
+    assert resp.status_code == 200
+    return resp.json()["transaction_hashes"]
+
+
+@devnet_in_background(*PREDEPLOY_ACCOUNT_CLI_ARGS)
+def test_contract_transactions():
+    """Deploy and invoke a contract; expect both txs to be listed"""
+    deploy_info = declare_and_deploy_with_chargeable(CONTRACT_PATH, inputs=["0"])
+    invoke_tx_hash = invoke(
+        calls=[(deploy_info["address"], "increase_balance", [10, 20])],
+        account_address=PREDEPLOYED_ACCOUNT_ADDRESS,
+        private_key=PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+    )
+
+    tx_hashes = get_contract_transactions(deploy_info["address"])
+    assert len(tx_hashes) == 2
+    assert_hex_equal(tx_hashes[0], deploy_info["tx_hash"])
+    assert_hex_equal(tx_hashes[1], invoke_tx_hash)
+
+    # the account is the sender of the invoke
+    account_tx_hashes = get_contract_transactions(PREDEPLOYED_ACCOUNT_ADDRESS)
+    assert account_tx_hashes == [tx_hashes[1]]
+
+    invoke_block_number = get_transaction_receipt(invoke_tx_hash)["block_number"]
+    assert get_contract_transactions(
+        deploy_info["address"], fromBlock=invoke_block_number
+    ) == [tx_hashes[1]]
+    assert get_contract_transactions(
+        deploy_info["address"], toBlock=invoke_block_number - 1
+    ) == [tx_hashes[0]]
+
+
+@devnet_in_background()
+def test_contract_transactions_invalid_address():
+    """Expect an error if the address is not a hex string"""
+    resp = requests.get(
+        f"{APP_URL}/contract_transactions", params={"contractAddress": "123"}
+    )
+    assert resp.status_code == 400
+
+
+@devnet_in_background()
+def test_contract_transactions_invalid_block_range():
+    """Expect an error if the block range is malformed or reversed"""
+    for block_range in [
+        {"fromBlock": "abc"},
+        {"toBlock": "-1"},
+        {"fromBlock": "2", "toBlock": "1"},
+    ]:
+        resp = requests.get(
+            f"{APP_URL}/contract_transactions",
+            params={"contractAddress": PREDEPLOYED_ACCOUNT_ADDRESS, **block_range},
+        )
+        assert resp.status_code == 400, block_range
+        assert resp.json()["code"] == str(StarkErrorCode.MALFORMED_REQUEST)
+
+
+def get_account_fees_paid(address: str, **block_range) -> int:
+    """Get the sum of fees paid by transactions sent from the account"""
+    resp = requests.get(
+        f"{APP_URL}/account_fees_paid", params={"address": address, **block_range}
+    )
+    assert resp.status_code == 200
+    return resp.json()["amount"]
+
+
+@devnet_in_background(*PREDEPLOY_ACCOUNT_CLI_ARGS)
+def test_account_fees_paid():
+    """Send several txs from an account; expect the fees to match the receipts"""
+    deploy_info = declare_and_deploy_with_chargeable(CONTRACT_PATH, inputs=["0"])
+    assert get_account_fees_paid(PREDEPLOYED_ACCOUNT_ADDRESS) == 0
+
+    receipts = []
+    for _ in range(3):
+        invoke_tx_hash = invoke(
+            calls=[(deploy_info["address"], "increase_balance", [10, 20])],
+            account_address=PREDEPLOYED_ACCOUNT_ADDRESS,
+            private_key=PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+        )
+        receipts.append(get_transaction_receipt(invoke_tx_hash))
+
+    actual_fees = [int(receipt["actual_fee"], 16) for receipt in receipts]
+    assert get_account_fees_paid(PREDEPLOYED_ACCOUNT_ADDRESS) == sum(actual_fees)
+
+    second_block_number = receipts[1]["block_number"]
+    assert (
+        get_account_fees_paid(
+            PREDEPLOYED_ACCOUNT_ADDRESS,
+            fromBlock=second_block_number,
+            toBlock=second_block_number,
+        )
+        == actual_fees[1]
+    )
+
+
+@devnet_in_background()
+def test_account_fees_paid_invalid_block_range():
+    """Expect an error if the block range is malformed or reversed"""
+    for block_range in [
+        {"fromBlock": "abc"},
+        {"toBlock": "-1"},
+        {"fromBlock": "2", "toBlock": "1"},
+    ]:
+        resp = requests.get(
+            f"{APP_URL}/account_fees_paid",
+            params={"address": PREDEPLOYED_ACCOUNT_ADDRESS, **block_range},
+        )
+        assert resp.status_code == 400, block_range
+        assert resp.json()["code"] == str(StarkErrorCode.MALFORMED_REQUEST)
@@ -6,6 +6,11 @@ import pytest
 import requests
 from starkware.starknet.definitions.error_codes import StarknetErrorCode
 
+from starknet_devnet.constants import STARKNET_CLI_ACCOUNT_CLASS_HASH
+from starknet_devnet.contract_class_wrapper import DEFAULT_ACCOUNT_HASH
+from starknet_devnet.fee_token import FeeToken
+from starknet_devnet.udc import UDC
+
 from .account import declare
 from .settings import APP_URL
 from .shared import (
@@ -22,9 +27,22 @@ from .util import (
     assert_tx_status,
     assert_undeclared_class,
     devnet_in_background,
+    get_block,
     get_compiled_class_by_class_hash,
 )
 
+PREDECLARED_CLASS_HASHES = [
+    hex(FeeToken.HASH),
+    hex(UDC.HASH),
+    hex(DEFAULT_ACCOUNT_HASH),
+    hex(STARKNET_CLI_ACCOUNT_CLASS_HASH),
+]
+
+
+def get_class_hashes(params=None):
+    """Get hashes of declared classes"""
+    return requests.get(f"{APP_URL}/class_hashes", params=params)
+
 
 @pytest.mark.declare
 @devnet_in_background(*PREDEPLOY_ACCOUNT_CLI_ARGS)
@@ -63,3 +81,42 @@ def test_declare_happy_path():
     assert_class_by_hash(class_hash, CONTRACT_PATH)
 
     assert_undeclared_class(resp=get_compiled_class_by_class_hash(class_hash))
+
+
+@pytest.mark.declare
+@devnet_in_background(*PREDEPLOY_ACCOUNT_CLI_ARGS)
+def test_class_hashes():
+    """Test listing hashes of declared classes"""
+    resp = get_class_hashes()
+    assert resp.status_code == 200, resp.json()
+    assert resp.json()["class_hashes"] == PREDECLARED_CLASS_HASHES
+    genesis_block_number = get_block(block_number="latest")["block_number"]
+
+    declare(
+        contract_path=CONTRACT_PATH,
+        account_address=PREDEPLOYED_ACCOUNT_ADDRESS,
+        private_key=PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+        max_fee=int(1e18),
+    )
+    expected_class_hash = hex(int(EXPECTED_CLASS_HASH, 16))
+
+    resp = get_class_hashes()
+    assert resp.json()["class_hashes"] == [
+        *PREDECLARED_CLASS_HASHES,
+        expected_class_hash,
+    ]
+
+    # classes declared after the requested block are not listed
+    resp = get_class_hashes({"blockNumber": genesis_block_number})
+    assert resp.json()["class_hashes"] == PREDECLARED_CLASS_HASHES
+
+
+@pytest.mark.declare
+@devnet_in_background()
+def test_class_hashes_invalid_block_number():
+    """Test listing hashes of declared classes with an invalid block number"""
+    resp = get_class_hashes({"blockNumber": "latest"})
+    assert resp.status_code == 400
+    assert resp.json()["message"] == (
+        "blockNumber value must be a non-negative integer; got: latest."
+    )
@@ -7,6 +7,7 @@ import requests
 from starkware.starknet.core.os.contract_class.compiled_class_hash import (
     compute_compiled_class_hash,
 )
+from starkware.starknet.definitions.error_codes import StarknetErrorCode
 from starkware.starknet.services.api.contract_class.contract_class import (
     CompiledClass,
     ContractClass,
@@ -129,6 +130,54 @@ def test_redeclaring_v2():
     )
 
 
+@pytest.mark.declare
+@devnet_in_background(*PREDEPLOY_ACCOUNT_CLI_ARGS, "--lenient-declare")
+def test_redeclaring_v2_leniently():
+    """Should respond with the original declaration if redeclaring in lenient mode"""
+    contract_class, _, compiled_class_hash = load_cairo1_contract()
+    declaration_resp = send_declare_v2(
+        contract_class=contract_class,
+        compiled_class_hash=compiled_class_hash,
+        sender_address=PREDEPLOYED_ACCOUNT_ADDRESS,
+        sender_key=PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+    )
+    assert_declare_v2_accepted(declaration_resp)
+
+    redeclaration_resp = send_declare_v2(
+        contract_class=contract_class,
+        compiled_class_hash=compiled_class_hash,
+        sender_address=PREDEPLOYED_ACCOUNT_ADDRESS,
+        sender_key=PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+    )
+    assert_declare_v2_accepted(redeclaration_resp)
+    assert redeclaration_resp.json() == declaration_resp.json()
+
+
+@pytest.mark.declare
+@devnet_in_background(*PREDEPLOY_ACCOUNT_CLI_ARGS, "--lenient-declare")
+def test_redeclaring_v2_leniently_with_invalid_signature():
+    """Should reject an invalidly signed redeclaration even in lenient mode"""
+    contract_class, _, compiled_class_hash = load_cairo1_contract()
+    declaration_resp = send_declare_v2(
+        contract_class=contract_class,
+        compiled_class_hash=compiled_class_hash,
+        sender_address=PREDEPLOYED_ACCOUNT_ADDRESS,
+        sender_key=PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+    )
+    assert_declare_v2_accepted(declaration_resp)
+
+    redeclaration_resp = send_declare_v2(
+        contract_class=contract_class,
+        compiled_class_hash=compiled_class_hash,
+        sender_address=PREDEPLOYED_ACCOUNT_ADDRESS,
+        sender_key=0x123,  # key to cause invalid signature
+    )
+    assert redeclaration_resp.status_code != 200
+    assert redeclaration_resp.json()["code"] == str(
+        StarknetErrorCode.TRANSACTION_FAILED
+    )
+
+
 @pytest.mark.declare
 @devnet_in_background(*PREDEPLOY_ACCOUNT_CLI_ARGS)
 def test_classes_available_after_declare_v2():
@@ -1,6 +1,7 @@
 """Test devnet contract deployment"""
 
 import pytest
+import requests
 from starkware.starknet.core.os.contract_address.contract_address import (
     calculate_contract_address,
 )
@@ -21,11 +22,13 @@ from starkware.starknet.wallets.open_zeppelin import (
     sign_deploy_account_tx,
     sign_invoke_tx,
 )
+from starkware.starkware_utils.error_handling import StarkErrorCode
 
 from starknet_devnet.constants import STARKNET_CLI_ACCOUNT_CLASS_HASH
 from starknet_devnet.udc import UDC
 
-from .account import declare, declare_and_deploy_with_chargeable
+from .account import declare, declare_and_deploy_with_chargeable, deploy
+from .settings import APP_URL
 from .shared import (
     ABI_PATH,
     CONTRACT_PATH,
@@ -38,6 +41,7 @@ from .shared import (
     STARKNET_CLI_ACCOUNT_ABI_PATH,
     SUPPORTED_TX_VERSION,
 )
+from .test_account import get_account_balance
 from .util import (
     assert_class_by_hash,
     assert_equal,
@@ -45,6 +49,7 @@ from .util import (
     assert_tx_status,
     call,
     devnet_in_background,
+    get_block,
     get_class_hash_at,
     get_transaction_receipt,
     load_contract_class,
@@ -153,6 +158,198 @@ def deploy_account_test_body():
     assert balance_after == "40"
 
 
+def compute_contract_address(**params) -> requests.Response:
+    """Send a request for computing the address of a contract"""
+    return requests.post(f"{APP_URL}/compute_contract_address", json=params)
+
+
+@devnet_in_background()
+def test_compute_contract_address():
+    """Test that the computed address matches the address of a UDC deployment"""
+    deploy_info = declare_and_deploy_with_chargeable(
+        contract=CONTRACT_PATH, inputs=["10"], salt="0x42"
+    )
+    tx_receipt = get_transaction_receipt(tx_hash=deploy_info["tx_hash"])
+    deployed_address = tx_receipt["events"][0]["data"][0]  # address in UDC event
+
+    resp = compute_contract_address(
+        classHash=deploy_info["class_hash"],
+        salt="0x42",
+        constructorCalldata=["0xa"],
+        deployFromZero=True,
+    )
+    assert resp.status_code == 200
+    assert_hex_equal(resp.json()["contract_address"], deployed_address)
+
+    # the deployer matters only if not deploying from zero
+    resp = compute_contract_address(
+        classHash=deploy_info["class_hash"],
+        salt="0x42",
+        constructorCalldata=["0xa"],
+        deployerAddress=EXPECTED_UDC_ADDRESS,
+    )
+    assert resp.status_code == 200
+    assert int(resp.json()["contract_address"], 16) != int(deployed_address, 16)
+
+
+@devnet_in_background(*PREDEPLOY_ACCOUNT_CLI_ARGS)
+def test_compute_contract_address_of_unique_udc_deployment():
+    """Test that the computed address matches the address of a unique UDC deployment"""
+    class_hash = declare(
+        contract_path=CONTRACT_PATH,
+        account_address=PREDEPLOYED_ACCOUNT_ADDRESS,
+        private_key=PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+        max_fee=int(1e18),
+    )["class_hash"]
+    deploy_info = deploy(
+        class_hash=class_hash,
+        account_address=PREDEPLOYED_ACCOUNT_ADDRESS,
+        private_key=PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+        inputs=["10"],
+        salt="0x42",
+        unique=True,
+    )
+    tx_receipt = get_transaction_receipt(tx_hash=deploy_info["tx_hash"])
+    deployed_address = tx_receipt["events"][0]["data"][0]  # address in UDC event
+
+    resp = compute_contract_address(
+        classHash=class_hash,
+        salt="0x42",
+        constructorCalldata=["0xa"],
+        deployerAddress=PREDEPLOYED_ACCOUNT_ADDRESS,
+        unique=True,
+    )
+    assert resp.status_code == 200
+    assert_hex_equal(resp.json()["contract_address"], deployed_address)
+
+
+@devnet_in_background()
+def test_compute_contract_address_unique_from_zero():
+    """Test that a unique deployment from zero is rejected"""
+    resp = compute_contract_address(
+        classHash="0x1",
+        salt="0x2",
+        constructorCalldata=[],
+        deployFromZero=True,
+        unique=True,
+    )
+    assert resp.status_code == 400
+    assert resp.json()["code"] == str(StarkErrorCode.MALFORMED_REQUEST)
+
+
+@devnet_in_background()
+def test_compute_contract_address_invalid_calldata():
+    """Test that calldata which is not a list of hex strings is rejected"""
+    resp = compute_contract_address(
+        classHash="0x1", salt="0x2", constructorCalldata="0xa", deployFromZero=True
+    )
+    assert resp.status_code == 400
+    assert resp.json()["code"] == str(StarkErrorCode.MALFORMED_REQUEST)
+
+
+AUTO_FUND_AMOUNT = int(1e18)
+
+
+def _sign_deploy_account_with_salt(salt: int):
+    """Sign a deploy account tx of an account with fixed keys; return address and tx"""
+    public_key = 0x7707342F75277F32F1A0AD532E1A12016B36A3967332D31F915C889678B3DB6
+    account_address, deploy_account_tx = sign_deploy_account_tx(
+        private_key=0x6F9E0F15B20753CE2E2B740B182099C4ADF765D0C5A5B75C1AF3327358FBF2E,
+        public_key=public_key,
+        class_hash=compute_deprecated_class_hash(oz_account_class),
+        salt=salt,
+        max_fee=int(1e15),
+        version=SUPPORTED_TX_VERSION,
+        chain_id=DEFAULT_CHAIN_ID,
+        nonce=0,
+    )
+    return hex(account_address), deploy_account_tx.dump()
+
+
+@devnet_in_background("--auto-fund-deploy-account", str(AUTO_FUND_AMOUNT))
+def test_deploy_account_auto_funded():
+    """Test that an unfunded account is funded before deployment"""
+    account_address, deploy_account_tx = _sign_deploy_account_with_salt(1)
+    tx = send_tx(deploy_account_tx, TransactionType.DEPLOY_ACCOUNT)
+    assert_tx_status(tx["transaction_hash"], "ACCEPTED_ON_L2")
+
+    balance = get_account_balance(account_address)
+    assert 0 < balance < AUTO_FUND_AMOUNT
+
+
+@devnet_in_background("--auto-fund-deploy-account", str(AUTO_FUND_AMOUNT))
+def test_deploy_account_auto_funded_with_next_transaction_hash():
+    """Test that auto-funding neither consumes the chosen hash nor creates a block"""
+    _, deploy_account_tx = _sign_deploy_account_with_salt(3)
+    chosen_tx_hash = "0x123abc"
+    resp = requests.post(
+        f"{APP_URL}/set_next_transaction_hash", json={"transactionHash": chosen_tx_hash}
+    )
+    assert resp.status_code == 200
+    block_number_before = get_block()["block_number"]
+
+    tx = send_tx(deploy_account_tx, TransactionType.DEPLOY_ACCOUNT)
+    assert tx["transaction_hash"] == chosen_tx_hash
+    assert_tx_status(chosen_tx_hash, "ACCEPTED_ON_L2")
+
+    latest_block = get_block()
+    assert latest_block["block_number"] == block_number_before + 1
+    assert [
+        block_tx["transaction_hash"] for block_tx in latest_block["transactions"]
+    ] == [chosen_tx_hash]
+
+
+@devnet_in_background("--auto-fund-deploy-account", str(AUTO_FUND_AMOUNT))
+def test_deploy_account_auto_funding_undone_if_rejected():
+    """Test that the funding is not kept if the deploy account tx is rejected"""
+    account_address, deploy_account_tx = _sign_deploy_account_with_salt(4)
+    deploy_account_tx["signature"] = ["0x1", "0x2"]
+
+    resp = requests.post(
+        f"{APP_URL}/gateway/add_transaction",
+        json={**deploy_account_tx, "type": TransactionType.DEPLOY_ACCOUNT.name},
+    )
+    assert resp.status_code != 200
+    assert resp.json()["code"] == str(StarknetErrorCode.TRANSACTION_FAILED)
+
+    assert get_account_balance(account_address) == 0
+
+
+@devnet_in_background("--auto-fund-deploy-account", str(AUTO_FUND_AMOUNT))
+def test_deploy_account_auto_funding_undone_if_constructor_fails():
+    """Test that the funding is not kept if the account constructor fails"""
+    _, deploy_account_tx = _sign_deploy_account_with_salt(5)
+    # the account constructor expects the public key
+    deploy_account_tx["constructor_calldata"] = []
+    account_address = calculate_contract_address(
+        salt=5,
+        contract_class=oz_account_class,
+        constructor_calldata=[],
+        deployer_address=0,
+    )
+
+    requests.post(
+        f"{APP_URL}/gateway/add_transaction",
+        json={**deploy_account_tx, "type": TransactionType.DEPLOY_ACCOUNT.name},
+    )
+
+    assert get_account_balance(hex(account_address)) == 0
+
+
+@devnet_in_background("--auto-fund-deploy-account", str(AUTO_FUND_AMOUNT))
+def test_deploy_account_funded_not_auto_funded():
+    """Test that an account with funds is not funded again before deployment"""
+    account_address, deploy_account_tx = _sign_deploy_account_with_salt(2)
+    initial_balance = int(1e16)
+    mint(account_address, amount=initial_balance)
+
+    tx = send_tx(deploy_account_tx, TransactionType.DEPLOY_ACCOUNT)
+    assert_tx_status(tx["transaction_hash"], "ACCEPTED_ON_L2")
+
+    balance = get_account_balance(account_address)
+    assert 0 < balance < initial_balance
+
+
 def assert_deployed_through_syscall(tx_hash: str, initial_balance: int):
     """Asserts that a contract has been deployed using the deploy syscall"""
     assert_tx_status(tx_hash, "ACCEPTED_ON_L2")
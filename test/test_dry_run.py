@@ -0,0 +1,146 @@
+"""Test dry-running transactions"""
+
+import requests
+from starkware.starknet.definitions.error_codes import StarknetErrorCode
+from starkware.starknet.services.api.gateway.transaction import DeprecatedDeclare
+from starkware.starkware_utils.error_handling import StarkErrorCode
+
+from starknet_devnet.account_util import get_execute_args
+
+from .account import declare_and_deploy_with_chargeable, get_nonce, invoke
+from .settings import APP_URL
+from .shared import (
+    CONTRACT_PATH,
+    EXPECTED_FEE_TOKEN_ADDRESS,
+    PREDEPLOY_ACCOUNT_CLI_ARGS,
+    PREDEPLOYED_ACCOUNT_ADDRESS,
+    PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+    STORAGE_CONTRACT_PATH,
+    SUPPORTED_TX_VERSION,
+)
+from .util import devnet_in_background, get_block, load_contract_class
+
+MAX_FEE = int(1e16)
+
+
+def _get_invoke_tx_dict(contract_address: str, value: int, nonce=None) -> dict:
+    if nonce is None:
+        nonce = get_nonce(PREDEPLOYED_ACCOUNT_ADDRESS)
+    signature, execute_calldata = get_execute_args(
+        calls=[(contract_address, "store_value", [value])],
+        account_address=PREDEPLOYED_ACCOUNT_ADDRESS,
+        private_key=PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+        nonce=nonce,
+        version=SUPPORTED_TX_VERSION,
+        max_fee=MAX_FEE,
+    )
+
+    return {
+        "sender_address": PREDEPLOYED_ACCOUNT_ADDRESS,
+        "max_fee": hex(MAX_FEE),
+        "calldata": [str(element) for element in execute_calldata],
+        "version": hex(SUPPORTED_TX_VERSION),
+        "nonce": hex(nonce),
+        "signature": signature,
+        "type": "INVOKE_FUNCTION",
+    }
+
+
+def _add_transaction(tx_dict: dict, dry_run=None):
+    params = {} if dry_run is None else {"dryRun": dry_run}
+    return requests.post(
+        f"{APP_URL}/gateway/add_transaction", json=tx_dict, params=params
+    )
+
+
+@devnet_in_background(*PREDEPLOY_ACCOUNT_CLI_ARGS)
+def test_dry_run_matches_real_submission():
+    """Dry-run receipt should match the receipt of the actually submitted tx"""
+    contract_address = declare_and_deploy_with_chargeable(STORAGE_CONTRACT_PATH)[
+        "address"
+    ]
+    tx_dict = _get_invoke_tx_dict(contract_address, value=42)
+
+    latest_block_before = get_block(block_number="latest")
+    nonce_before = get_nonce(PREDEPLOYED_ACCOUNT_ADDRESS)
+
+    dry_run_resp = _add_transaction(tx_dict, dry_run="true")
+    assert dry_run_resp.status_code == 200
+    dry_run_receipt = dry_run_resp.json()["receipt"]
+    assert dry_run_resp.json()["trace"]["function_invocation"]
+
+    # nothing persisted
+    assert get_block(block_number="latest") == latest_block_before
+    assert get_nonce(PREDEPLOYED_ACCOUNT_ADDRESS) == nonce_before
+    tx_status = requests.get(
+        f"{APP_URL}/feeder_gateway/get_transaction_status",
+        params={"transactionHash": dry_run_receipt["transaction_hash"]},
+    ).json()
+    assert tx_status["tx_status"] == "NOT_RECEIVED"
+
+    real_resp = _add_transaction(tx_dict)
+    assert real_resp.status_code == 200
+    real_receipt = requests.get(
+        f"{APP_URL}/feeder_gateway/get_transaction_receipt",
+        params={"transactionHash": real_resp.json()["transaction_hash"]},
+    ).json()
+
+    for prop in [
+        "transaction_hash",
+        "execution_status",
+        "block_number",
+        "transaction_index",
+        "actual_fee",
+        "events",
+        "l2_to_l1_messages",
+        "execution_resources",
+    ]:
+        assert dry_run_receipt[prop] == real_receipt[prop], prop
+
+
+@devnet_in_background(*PREDEPLOY_ACCOUNT_CLI_ARGS)
+def test_invalid_dry_run_value():
+    """Dry run flag should be a boolean"""
+    tx_dict = _get_invoke_tx_dict(EXPECTED_FEE_TOKEN_ADDRESS, value=1)
+    resp = _add_transaction(tx_dict, dry_run="maybe")
+    assert resp.status_code == 400
+    assert "dryRun" in resp.json()["message"]
+
+
+@devnet_in_background(*PREDEPLOY_ACCOUNT_CLI_ARGS)
+def test_dry_run_invalid_nonce():
+    """Dry run should reject a transaction which would be rejected if submitted"""
+    contract_address = declare_and_deploy_with_chargeable(STORAGE_CONTRACT_PATH)[
+        "address"
+    ]
+    invoke(
+        calls=[(contract_address, "store_value", [1])],
+        account_address=PREDEPLOYED_ACCOUNT_ADDRESS,
+        private_key=PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+    )
+    latest_block_before = get_block(block_number="latest")
+
+    # the nonce has already been used
+    tx_dict = _get_invoke_tx_dict(contract_address, value=42, nonce=0)
+    resp = _add_transaction(tx_dict, dry_run="true")
+    assert resp.status_code == 400
+    assert resp.json()["code"] == str(StarknetErrorCode.INVALID_TRANSACTION_NONCE)
+    assert get_block(block_number="latest") == latest_block_before
+
+
+@devnet_in_background(*PREDEPLOY_ACCOUNT_CLI_ARGS)
+def test_dry_run_declare_not_supported():
+    """Dry run of a declare transaction should be rejected"""
+    declaration_body = DeprecatedDeclare(
+        contract_class=load_contract_class(CONTRACT_PATH),
+        sender_address=int(PREDEPLOYED_ACCOUNT_ADDRESS, 16),
+        version=SUPPORTED_TX_VERSION,
+        max_fee=MAX_FEE,
+        signature=[],
+        nonce=get_nonce(PREDEPLOYED_ACCOUNT_ADDRESS),
+    ).dump()
+    declaration_body["type"] = "DECLARE"
+
+    resp = _add_transaction(declaration_body, dry_run="true")
+    assert resp.status_code == 400
+    assert resp.json()["code"] == str(StarkErrorCode.MALFORMED_REQUEST)
@@ -9,8 +9,14 @@ from asyncio import subprocess
 
 import pytest
 import requests
+from starkware.starknet.core.os.transaction_hash.transaction_hash import (
+    TransactionHashPrefix,
+    calculate_transaction_hash_common,
+)
+
+from starknet_devnet.account_util import get_execute_args
 
-from .account import declare_and_deploy_with_chargeable, invoke
+from .account import declare_and_deploy_with_chargeable, get_nonce, invoke
 from .settings import APP_URL
 from .shared import (
     ABI_PATH,
@@ -18,10 +24,19 @@ from .shared import (
     PREDEPLOY_ACCOUNT_CLI_ARGS,
     PREDEPLOYED_ACCOUNT_ADDRESS,
     PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+    SUPPORTED_TX_VERSION,
 )
 from .test_account import get_account_balance
+from .test_chain_id_cli_params import CUSTOM_CHAIN_ID
 from .test_fee_token import mint
-from .util import DevnetBackgroundProc, call, devnet_in_background, terminate_and_wait
+from .util import (
+    DevnetBackgroundProc,
+    assert_hex_equal,
+    assert_tx_status,
+    call,
+    devnet_in_background,
+    terminate_and_wait,
+)
 
 DUMP_PATH = "dump.pkl"
 
@@ -321,7 +336,19 @@ def test_invalid_dump_on_option():
     )
 
     assert devnet_proc.returncode == 1
-    expected_msg = b"Error: Invalid --dump-on option: obviously-invalid. Valid options: exit, transaction\n"
+    expected_msg = b"Error: Invalid --dump-on option: obviously-invalid. Valid options: exit, transaction, blocks:<N>\n"
+    assert expected_msg in devnet_proc.stderr.read()
+
+
+@pytest.mark.parametrize("dump_on", ["blocks", "blocks:0", "blocks:-1", "blocks:x"])
+def test_invalid_dump_on_blocks_option(dump_on):
+    """Test behavior when the block count of dumping on blocks is invalid."""
+    devnet_proc = ACTIVE_DEVNET.start(
+        "--dump-on", dump_on, "--dump-path", DUMP_PATH, stderr=subprocess.PIPE
+    )
+
+    assert devnet_proc.returncode == 1
+    expected_msg = f"Error: Invalid --dump-on option: {dump_on}.".encode()
     assert expected_msg in devnet_proc.stderr.read()
 
 
@@ -375,6 +402,45 @@ def test_dumping_on_each_tx():
     assert_load(dump_after_invoke_path, contract_address, "10")
 
 
+def test_dumping_on_blocks():
+    """Test dumping after every N created blocks."""
+    ACTIVE_DEVNET.start(
+        *PREDEPLOY_ACCOUNT_CLI_ARGS,
+        "--dump-on",
+        "blocks:2",
+        "--dump-path",
+        DUMP_PATH,
+    )
+
+    # declare and deploy create two blocks
+    contract_address = declare_and_deploy_empty_contract()
+    assert_dump_present(DUMP_PATH)
+    dump_after_deploy_path = "dump_after_deploy.pkl"
+    os.rename(DUMP_PATH, dump_after_deploy_path)
+
+    # a single block is not enough for another dump
+    invoke(
+        calls=[(contract_address, "increase_balance", ["5", "5"])],
+        account_address=PREDEPLOYED_ACCOUNT_ADDRESS,
+        private_key=PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+    )
+    assert_no_dump_present(DUMP_PATH)
+
+    invoke(
+        calls=[(contract_address, "increase_balance", ["1", "1"])],
+        account_address=PREDEPLOYED_ACCOUNT_ADDRESS,
+        private_key=PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+    )
+    assert_dump_present(DUMP_PATH)
+    dump_after_invokes_path = "dump_after_invokes.pkl"
+    os.rename(DUMP_PATH, dump_after_invokes_path)
+
+    ACTIVE_DEVNET.stop()
+
+    assert_load(dump_after_deploy_path, contract_address, "0")
+    assert_load(dump_after_invokes_path, contract_address, "12")
+
+
 @devnet_in_background()
 def test_dumping_call_with_invalid_body():
     """Call with invalid body and test status code and message."""
@@ -425,3 +491,63 @@ def test_calling_old_state_after_load():
 
     value_after_invoke = call_at_block("3")
     assert value_after_invoke == increment_value
+
+
+def _invoke_with_custom_chain_id(contract_address: str):
+    """Invoke with the custom chain id; assert the hash was computed with it"""
+    calls = [(contract_address, "increase_balance", [10, 20])]
+    nonce = get_nonce(PREDEPLOYED_ACCOUNT_ADDRESS)
+    max_fee = int(1e15)
+    tx_hash = invoke(
+        calls=calls,
+        account_address=PREDEPLOYED_ACCOUNT_ADDRESS,
+        private_key=PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+        nonce=nonce,
+        max_fee=max_fee,
+        chain_id=CUSTOM_CHAIN_ID,
+    )
+    assert_tx_status(tx_hash, "ACCEPTED_ON_L2")
+
+    _, execute_calldata = get_execute_args(
+        calls=calls,
+        account_address=PREDEPLOYED_ACCOUNT_ADDRESS,
+        private_key=PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+        nonce=nonce,
+        version=SUPPORTED_TX_VERSION,
+        max_fee=max_fee,
+        chain_id=CUSTOM_CHAIN_ID,
+    )
+    expected_tx_hash = calculate_transaction_hash_common(
+        tx_hash_prefix=TransactionHashPrefix.INVOKE,
+        version=SUPPORTED_TX_VERSION,
+        contract_address=int(PREDEPLOYED_ACCOUNT_ADDRESS, 16),
+        entry_point_selector=0,
+        calldata=execute_calldata,
+        max_fee=max_fee,
+        chain_id=CUSTOM_CHAIN_ID.value,
+        additional_data=[nonce],
+    )
+    assert_hex_equal(tx_hash, hex(expected_tx_hash))
+
+
+def test_custom_chain_id_after_load_and_restart():
+    """Transactions should be hashed with the custom chain id after load and restart"""
+    ACTIVE_DEVNET.start(*PREDEPLOY_ACCOUNT_CLI_ARGS, "--chain-id", CUSTOM_CHAIN_ID.name)
+    contract_address = declare_and_deploy_with_chargeable(
+        CONTRACT_PATH, inputs=["0"], chain_id=CUSTOM_CHAIN_ID
+    )["address"]
+    _invoke_with_custom_chain_id(contract_address)
+
+    dump_and_assert(DUMP_PATH)
+    ACTIVE_DEVNET.stop()
+    assert_not_alive()
+
+    ACTIVE_DEVNET.start("--load-path", DUMP_PATH)
+    assert requests.get(f"{APP_URL}/config").json()["chain_id"] == CUSTOM_CHAIN_ID.name
+    _invoke_with_custom_chain_id(contract_address)
+
+    assert requests.post(f"{APP_URL}/restart").status_code == 200
+    contract_address = declare_and_deploy_with_chargeable(
+        CONTRACT_PATH, inputs=["0"], chain_id=CUSTOM_CHAIN_ID
+    )["address"]
+    _invoke_with_custom_chain_id(contract_address)
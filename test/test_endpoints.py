@@ -9,7 +9,7 @@ import requests
 from starkware.starknet.definitions.error_codes import StarknetErrorCode
 from starkware.starkware_utils.error_handling import StarkErrorCode
 
-from starknet_devnet.constants import DEFAULT_GAS_PRICE
+from starknet_devnet.constants import DEFAULT_GAS_PRICE, MAX_CREATED_BLOCKS_COUNT
 from starknet_devnet.server import app
 
 from .account import declare_and_deploy_with_chargeable
@@ -331,6 +331,39 @@ def test_create_block_endpoint():
     assert resp.get("block_hash") == hex(GENESIS_BLOCK_NUMBER + 4)
 
 
+def create_blocks(count):
+    """Request the creation of `count` blocks"""
+    return requests.post(f"{APP_URL}/create_blocks", json={"count": count})
+
+
+@devnet_in_background()
+def test_create_blocks_endpoint():
+    """Test creating multiple empty blocks at once"""
+    resp = create_blocks(3)
+    assert resp.status_code == 200, resp.json()
+
+    first_block = get_block_by_number({"blockNumber": GENESIS_BLOCK_NUMBER + 1}).json()
+    assert resp.json()["first_block_hash"] == first_block["block_hash"]
+    assert first_block["transactions"] == []
+
+    latest_block = get_block_by_number({"blockNumber": "latest"}).json()
+    assert latest_block["block_number"] == GENESIS_BLOCK_NUMBER + 3
+    assert resp.json()["last_block_hash"] == latest_block["block_hash"]
+    assert latest_block["transactions"] == []
+
+
+@pytest.mark.parametrize("count", [0, MAX_CREATED_BLOCKS_COUNT + 1, "a"])
+@devnet_in_background()
+def test_create_blocks_invalid_count(count):
+    """Test that counts out of the allowed range are rejected"""
+    resp = create_blocks(count)
+    assert resp.status_code == 400
+    assert resp.json()["code"] == str(StarkErrorCode.MALFORMED_REQUEST)
+
+    latest_block = get_block_by_number({"blockNumber": "latest"}).json()
+    assert latest_block["block_number"] == GENESIS_BLOCK_NUMBER
+
+
 @devnet_in_background()
 def test_get_transaction_status():
     """Assert valid response schema"""
@@ -427,3 +460,17 @@ def test_calling_without_body():
     assert resp.status_code == 400
     assert resp.is_json
     assert resp.json.get("code") == str(StarkErrorCode.MALFORMED_REQUEST)
+
+
+@devnet_in_background("--request-body-size-limit", "1000")
+def test_request_body_size_limit():
+    """Test that request bodies exceeding the configured limit are rejected"""
+    mint_body = {"address": "0x1", "amount": 10}
+
+    resp = requests.post(f"{APP_URL}/mint", json={**mint_body, "padding": "a" * 900})
+    assert resp.status_code == 200, resp.json()
+
+    resp = requests.post(f"{APP_URL}/mint", json={**mint_body, "padding": "a" * 1000})
+    assert resp.status_code == 413
+    assert resp.json()["code"] == str(StarkErrorCode.MALFORMED_REQUEST)
+    assert "exceeds the limit of 1000 bytes" in resp.json()["message"]
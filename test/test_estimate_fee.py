@@ -11,6 +11,7 @@ from starkware.starknet.services.api.feeder_gateway.response_objects import (
     FeeEstimationInfo,
 )
 from starkware.starknet.services.api.gateway.transaction import AccountTransaction
+from starkware.starkware_utils.error_handling import StarkErrorCode
 
 from starknet_devnet.constants import DEFAULT_GAS_PRICE
 
@@ -302,3 +303,75 @@ def test_estimate_fee_bulk():
         function="get_balance", address=deploy_info["address"], abi_path=ABI_PATH
     )
     assert balance_after == initial_balance
+
+
+@devnet_in_background(*PREDEPLOY_ACCOUNT_CLI_ARGS, "--max-batch-size", "1")
+def test_estimate_fee_bulk_too_big():
+    """Test rejection of a bulk bigger than the configured maximum"""
+    # skip deployment; only the size of the bulk matters
+    invalid_address = "0x123"
+    tx_dicts = [
+        get_estimate_fee_request_dict(
+            calls=[(invalid_address, "increase_balance", [0, 0])],
+            account_address=PREDEPLOYED_ACCOUNT_ADDRESS,
+            private_key=PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+            nonce=nonce,
+        )
+        for nonce in range(2)
+    ]
+    txs = AccountTransaction.Schema().load(tx_dicts, many=True)
+
+    resp = _send_estimate_fee_bulk_request(txs)
+    assert resp.status_code == 400
+    assert resp.json()["code"] == str(StarkErrorCode.MALFORMED_REQUEST)
+    assert "the maximum is 1" in resp.json()["message"]
+
+    # a bulk within the limit gets executed
+    resp = _send_estimate_fee_bulk_request(txs[:1])
+    assert resp.json()["code"] == str(StarknetErrorCode.UNINITIALIZED_CONTRACT)
+
+
+@devnet_in_background(*PREDEPLOY_ACCOUNT_CLI_ARGS)
+def test_estimate_fee_with_gas_price_override():
+    """Test estimating the same transaction under different gas prices"""
+
+    deploy_info = declare_and_deploy_with_chargeable(CONTRACT_PATH, inputs=["0"])
+    tx_dict = get_estimate_fee_request_dict(
+        calls=[(deploy_info["address"], "increase_balance", [10, 20])],
+        account_address=PREDEPLOYED_ACCOUNT_ADDRESS,
+        private_key=PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+    )
+
+    fees = []
+    for gas_price in [DEFAULT_GAS_PRICE, 3 * DEFAULT_GAS_PRICE]:
+        resp = requests.post(
+            f"{APP_URL}/feeder_gateway/estimate_fee",
+            json=tx_dict,
+            params={"gasPrice": gas_price},
+        )
+        assert resp.status_code == 200, resp.json()
+        fee_estimation_info = FeeEstimationInfo.load(resp.json())
+        assert fee_estimation_info.gas_price == gas_price
+        fees.append(fee_estimation_info)
+
+    assert fees[0].gas_usage == fees[1].gas_usage
+    assert fees[1].overall_fee == 3 * fees[0].overall_fee
+
+    # the gas price of the instance is not changed
+    resp = send_estimate_fee_with_requests(tx_dict)
+    assert resp.status_code == 200, resp.json()
+    assert resp.json()["gas_price"] == DEFAULT_GAS_PRICE
+
+
+@devnet_in_background()
+def test_estimate_fee_with_invalid_gas_price_override():
+    """Test estimating fee with a malformed gas price"""
+    req_dict = json.loads(INVOKE_CONTENT)
+    resp = requests.post(
+        f"{APP_URL}/feeder_gateway/estimate_fee",
+        json=req_dict,
+        params={"gasPrice": "-1"},
+    )
+
+    assert resp.status_code == 400
+    assert "Invalid value for gasPrice" in resp.json()["message"]
@@ -1,6 +1,7 @@
 """Fee token related tests."""
 
 import json
+import subprocess
 
 import pytest
 import requests
@@ -10,10 +11,12 @@ from starkware.starknet.public.abi import get_selector_from_name
 from starknet_devnet.chargeable_account import ChargeableAccount
 from starknet_devnet.fee_token import FeeToken
 from starknet_devnet.server import app
+from starknet_devnet.util import Uint256, str_to_felt
 
 from .account import invoke
 from .settings import APP_URL
 from .shared import (
+    ARTIFACTS_PATH,
     EXPECTED_FEE_TOKEN_ADDRESS,
     GENESIS_BLOCK_NUMBER,
     PREDEPLOY_ACCOUNT_CLI_ARGS,
@@ -26,7 +29,21 @@ from .test_account import (
     get_account_balance,
     get_transaction_receipt,
 )
-from .util import ErrorExpector, assert_equal, devnet_in_background, mint
+from .util import (
+    DevnetBackgroundProc,
+    ErrorExpector,
+    assert_equal,
+    call,
+    demand_block_creation,
+    devnet_in_background,
+    get_block,
+    mint,
+    read_stream,
+)
+
+FEE_TOKEN_ABI_PATH = f"{ARTIFACTS_PATH}/fee_token_abi.json"
+with open(FEE_TOKEN_ABI_PATH, "w", encoding="utf-8") as fee_token_abi_file:
+    json.dump(FeeToken.get_contract_class().abi, fee_token_abi_file)
 
 
 @pytest.mark.fee_token
@@ -223,3 +240,252 @@ def test_increase_balance():
     assert_equal(
         final_account_balance, initial_account_balance + mint_amount - actual_fee
     )
+
+
+def get_sequencer_balance() -> int:
+    """Get balance (wei) of the sequencer"""
+    resp = requests.get(f"{APP_URL}/sequencer_balance")
+    assert resp.status_code == 200, resp.json()
+    return int(resp.json()["amount"])
+
+
+@pytest.mark.fee_token
+@devnet_in_background(*PREDEPLOY_ACCOUNT_CLI_ARGS)
+def test_sequencer_collects_fees():
+    """Assert the sequencer balance grows by the fee of each transaction"""
+    deploy_info = deploy_empty_contract()
+    initial_sequencer_balance = get_sequencer_balance()
+
+    calls = [(deploy_info["address"], "increase_balance", ["10", "20"])]
+    for _ in range(2):
+        invoke_tx_hash = invoke(
+            calls=calls,
+            account_address=PREDEPLOYED_ACCOUNT_ADDRESS,
+            private_key=PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+        )
+        assert_tx_status(invoke_tx_hash, "ACCEPTED_ON_L2")
+
+        actual_fee = int(get_transaction_receipt(invoke_tx_hash)["actual_fee"], 16)
+        assert actual_fee > 0
+
+        sequencer_balance = get_sequencer_balance()
+        assert_equal(sequencer_balance, initial_sequencer_balance + actual_fee)
+        initial_sequencer_balance = sequencer_balance
+
+
+def get_total_supply() -> int:
+    """Get the total supply of the fee token"""
+    low, high = call(
+        "totalSupply", address=hex(FeeToken.ADDRESS), abi_path=FEE_TOKEN_ABI_PATH
+    ).split()
+    return Uint256(low=int(low), high=int(high)).to_felt()
+
+
+@pytest.mark.fee_token
+@devnet_in_background(
+    "--accounts", "3", "--initial-balance", "100", "--initial-total-supply", "1000"
+)
+def test_initial_total_supply():
+    """Assert the total supply includes the configured value and predeployed funds"""
+    assert_equal(get_total_supply(), 1000 + 3 * 100)
+
+    mint(address=PREDEPLOYED_ACCOUNT_ADDRESS, amount=50, lite=True)
+    assert_equal(get_total_supply(), 1000 + 3 * 100 + 50)
+
+
+@pytest.mark.fee_token
+@devnet_in_background()
+def test_total_supply_not_initialized_by_default():
+    """Assert the total supply is not set if not configured"""
+    assert_equal(get_total_supply(), 0)
+
+
+@pytest.mark.fee_token
+def test_initial_total_supply_too_big():
+    """Assert devnet fails to start if the total supply does not fit into uint256"""
+    proc = DevnetBackgroundProc().start(
+        "--accounts",
+        "1",
+        "--initial-balance",
+        "1",
+        "--initial-total-supply",
+        str(2**256 - 1),
+        stderr=subprocess.PIPE,
+        stdout=subprocess.PIPE,
+    )
+    assert "Error: --initial-total-supply together with the funds of" in read_stream(
+        proc.stderr
+    )
+    assert proc.returncode == 1
+
+
+@pytest.mark.fee_token
+@devnet_in_background(*PREDEPLOY_ACCOUNT_CLI_ARGS)
+def test_balance_at_block():
+    """Assert the balance can be read from past blocks"""
+    initial_balance = get_account_balance(PREDEPLOYED_ACCOUNT_ADDRESS)
+
+    mint_amount = 10
+    mint(address=PREDEPLOYED_ACCOUNT_ADDRESS, amount=mint_amount)
+    mint(address=PREDEPLOYED_ACCOUNT_ADDRESS, amount=mint_amount)
+
+    for block_number, expected_balance in [
+        (GENESIS_BLOCK_NUMBER, initial_balance),
+        (GENESIS_BLOCK_NUMBER + 1, initial_balance + mint_amount),
+        (GENESIS_BLOCK_NUMBER + 2, initial_balance + 2 * mint_amount),
+        ("latest", initial_balance + 2 * mint_amount),
+    ]:
+        balance = get_account_balance(
+            PREDEPLOYED_ACCOUNT_ADDRESS, block_number=block_number
+        )
+        assert_equal(balance, expected_balance)
+
+    resp = requests.get(
+        f"{APP_URL}/account_balance",
+        params={"address": PREDEPLOYED_ACCOUNT_ADDRESS, "blockNumber": 42},
+    )
+    assert resp.status_code == 400
+    assert resp.json()["code"] == str(StarknetErrorCode.BLOCK_NOT_FOUND)
+
+
+def mint_multiple(mints: list, lite=False):
+    """Send a request for minting to multiple addresses"""
+    return requests.post(
+        f"{APP_URL}/mint_multiple", json={"mints": mints, "lite": lite}
+    )
+
+
+@pytest.mark.fee_token
+@devnet_in_background()
+def test_mint_multiple():
+    """Assert all addresses are minted to, in the requested order"""
+    resp = mint_multiple(
+        [{"address": "0x123", "amount": 10}, {"address": "0x456", "amount": 20}]
+    )
+    assert resp.status_code == 200, resp.json()
+
+    results = resp.json()["mints"]
+    assert [result["new_balance"] for result in results] == [10, 20]
+    assert all(result["unit"] == "wei" for result in results)
+    assert get_account_balance("0x123") == 10
+    assert get_account_balance("0x456") == 20
+
+    latest_block = get_block(block_number="latest")
+    assert latest_block["block_number"] == GENESIS_BLOCK_NUMBER + 2
+    assert latest_block["transactions"][0]["transaction_hash"] == results[1]["tx_hash"]
+
+
+@pytest.mark.fee_token
+@devnet_in_background("--blocks-on-demand")
+def test_mint_multiple_on_demand():
+    """Assert all mint transactions end up in a single block"""
+    resp = mint_multiple(
+        [{"address": "0x123", "amount": 10}, {"address": "0x456", "amount": 20}]
+    )
+    assert resp.status_code == 200, resp.json()
+    tx_hashes = [result["tx_hash"] for result in resp.json()["mints"]]
+
+    demand_block_creation()
+    latest_block = get_block(block_number="latest")
+    assert latest_block["block_number"] == GENESIS_BLOCK_NUMBER + 1
+    assert [tx["transaction_hash"] for tx in latest_block["transactions"]] == tx_hashes
+
+
+@pytest.mark.fee_token
+@devnet_in_background()
+def test_mint_multiple_invalid_entry():
+    """Assert nothing is minted if any of the entries is invalid"""
+    resp = mint_multiple(
+        [{"address": "0x123", "amount": 10}, {"address": "0x456", "amount": -20}]
+    )
+    assert resp.status_code == 400
+    assert resp.json()["message"] == "amount value must be greater than 0."
+
+    assert get_account_balance("0x123") == 0
+    assert get_block(block_number="latest")["block_number"] == GENESIS_BLOCK_NUMBER
+
+
+def test_mint_multiple_not_a_list():
+    """Assert failure if mints are not provided as a list"""
+    resp = app.test_client().post(
+        "/mint_multiple",
+        content_type="application/json",
+        data=json.dumps({"mints": {"address": "0x1", "amount": 10}}),
+    )
+    assert resp.status_code == 400
+    assert resp.json["message"] == (
+        "mints value must be a list of objects with address and amount."
+    )
+
+
+MOCK_TOKEN_ADDRESS = "0x123"
+
+
+@pytest.mark.fee_token
+@devnet_in_background("--predeploy-erc20", f"Mock Token:MCK:{MOCK_TOKEN_ADDRESS}")
+def test_predeployed_erc20():
+    """Assert an additional token is predeployed and mintable"""
+    for function, expected in [("name", "Mock Token"), ("symbol", "MCK")]:
+        value = call(function, address=MOCK_TOKEN_ADDRESS, abi_path=FEE_TOKEN_ABI_PATH)
+        assert_equal(int(value), str_to_felt(expected))
+
+    resp = requests.post(
+        f"{APP_URL}/mint",
+        json={"address": "0x456", "amount": 10, "contractAddress": MOCK_TOKEN_ADDRESS},
+    )
+    assert resp.status_code == 200, resp.json()
+    assert resp.json()["new_balance"] == 10
+    assert resp.json()["unit"] == "MCK"
+
+    low, high = call(
+        "balanceOf",
+        address=MOCK_TOKEN_ADDRESS,
+        abi_path=FEE_TOKEN_ABI_PATH,
+        inputs=[str(0x456)],
+    ).split()
+    assert_equal(Uint256(low=int(low), high=int(high)).to_felt(), 10)
+
+    # the fee token balance is not affected
+    assert_equal(get_account_balance("0x456"), 0)
+
+    config = requests.get(f"{APP_URL}/config").json()
+    assert config["predeployed_erc20s"] == [
+        {"name": "Mock Token", "symbol": "MCK", "address": MOCK_TOKEN_ADDRESS}
+    ]
+
+
+@pytest.mark.fee_token
+@devnet_in_background()
+def test_mint_unknown_token():
+    """Assert minting fails if there is no predeployed token at the address"""
+    resp = requests.post(
+        f"{APP_URL}/mint",
+        json={"address": "0x456", "amount": 10, "contractAddress": MOCK_TOKEN_ADDRESS},
+    )
+    assert resp.status_code == 400
+    assert resp.json()["code"] == str(StarknetErrorCode.UNINITIALIZED_CONTRACT)
+
+
+@pytest.mark.fee_token
+@pytest.mark.parametrize(
+    "token, expected_error",
+    [
+        ("MCK:0x123", "The value of --predeploy-erc20 must be of the form"),
+        ("Mock:MCK:123", "The value of ADDRESS of --predeploy-erc20 must be a 0x"),
+        ("Mock:MCK:0x123:0x1", "The value of --predeploy-erc20 must be of the form"),
+        (
+            f"Mock:MCK:{EXPECTED_FEE_TOKEN_ADDRESS}",
+            "--predeploy-erc20 cannot use the address of the fee token",
+        ),
+    ],
+)
+def test_invalid_predeployed_erc20(token: str, expected_error: str):
+    """Assert devnet fails to start if the token is not specified properly"""
+    proc = DevnetBackgroundProc().start(
+        "--predeploy-erc20",
+        token,
+        stderr=subprocess.PIPE,
+        stdout=subprocess.PIPE,
+    )
+    assert expected_error in read_stream(proc.stderr)
+    assert proc.returncode == 1
@@ -3,10 +3,14 @@ Test the forking feature.
 Relying on the fact that devnet doesn't support specifying which block to query
 """
 
+import subprocess
+
 import pytest
 import requests
+from starkware.starknet.definitions.general_config import StarknetChainId
 
 from starknet_devnet.constants import DEFAULT_INITIAL_BALANCE
+from starknet_devnet.origin import get_forwarded_queries
 
 from .account import declare_and_deploy_with_chargeable, get_nonce, invoke
 from .settings import APP_URL, HOST, bind_free_port
@@ -33,7 +37,9 @@ from .util import (
     assert_tx_status,
     call,
     devnet_in_background,
+    get_block,
     mint,
+    read_stream,
 )
 
 ORIGIN_PORT, ORIGIN_URL = bind_free_port(HOST)
@@ -334,6 +340,7 @@ def test_fork_status_forked():
     data = resp.json()
     assert data.get("url") == ALPHA_GOERLI2_URL
     assert data.get("block") is not None
+    assert data.get("forwarded_queries") == get_forwarded_queries()
 
 
 @devnet_in_background(
@@ -355,3 +362,211 @@ def test_fork_status_not_forked():
     assert resp.status_code == 200
     data = resp.json()
     assert data == {}
+
+
+@devnet_in_background()
+def test_health_not_forked():
+    """Test GET on /health when not forking"""
+    resp = requests.get(f"{APP_URL}/health")
+    assert resp.status_code == 200
+    assert resp.json() == {
+        "status": "healthy",
+        "fork_origin_reachable": None,
+        "latest_block_number": 0,
+    }
+
+
+def test_health_reports_fork_origin_reachability():
+    """Test GET on /health when the origin goes down mid-session"""
+    origin_devnet = DevnetBackgroundProc()
+    try:
+        origin_devnet.start("--port", ORIGIN_PORT, "--accounts", "0")
+        FORKING_DEVNET.start(
+            "--port", FORK_PORT, "--fork-network", ORIGIN_URL, "--accounts", "0"
+        )
+
+        resp = requests.get(f"{FORK_URL}/health")
+        assert resp.status_code == 200
+        assert resp.json() == {
+            "status": "healthy",
+            "fork_origin_reachable": True,
+            "latest_block_number": 1,  # origin genesis + fork genesis
+        }
+
+        origin_devnet.stop()
+        resp = requests.get(f"{FORK_URL}/health")
+        assert resp.status_code == 503
+        assert resp.json()["status"] == "unhealthy"
+        assert resp.json()["fork_origin_reachable"] is False
+
+        # the origin check can be skipped
+        resp = requests.get(f"{FORK_URL}/health", params={"checkOrigin": "false"})
+        assert resp.status_code == 200
+        assert resp.json()["fork_origin_reachable"] is None
+    finally:
+        origin_devnet.stop()
+
+
+def _create_empty_origin_blocks(n_blocks: int):
+    for _ in range(n_blocks):
+        resp = requests.post(f"{ORIGIN_URL}/create_block")
+        assert resp.status_code == 200
+
+
+@devnet_in_background("--port", ORIGIN_PORT, "--accounts", "0")
+def test_fork_block_relative_to_latest():
+    """Test forking a block specified relative to the latest block of the origin"""
+    _create_empty_origin_blocks(3)  # latest block of origin is 3
+
+    FORKING_DEVNET.start(
+        "--port",
+        FORK_PORT,
+        "--fork-network",
+        ORIGIN_URL,
+        "--fork-block",
+        "latest-2",
+        "--accounts",
+        "0",
+    )
+    resp = requests.get(f"{FORK_URL}/fork_status")
+    data = resp.json()
+    assert data.get("url") == ORIGIN_URL
+    assert data.get("block") == 1
+
+
+@pytest.mark.parametrize("fork_block", ["latest-4", "4"])
+@devnet_in_background("--port", ORIGIN_PORT, "--accounts", "0")
+def test_fork_block_beyond_origin_latest(fork_block: str):
+    """Test forking a block which the origin doesn't have yet"""
+    _create_empty_origin_blocks(3)  # latest block of origin is 3
+
+    proc = FORKING_DEVNET.start(
+        "--port",
+        FORK_PORT,
+        "--fork-network",
+        ORIGIN_URL,
+        "--fork-block",
+        fork_block,
+        "--accounts",
+        "0",
+        stderr=subprocess.PIPE,
+        stdout=subprocess.PIPE,
+    )
+    proc.wait()
+    assert "the latest block" in read_stream(proc.stderr)
+    assert proc.returncode == 1
+
+
+@devnet_in_background("--port", ORIGIN_PORT, "--accounts", "0")
+def test_fork_block_hash():
+    """Test forking a block specified by its hash"""
+    _create_empty_origin_blocks(3)
+    origin_block = get_block(block_number=1, feeder_gateway_url=ORIGIN_URL)
+
+    FORKING_DEVNET.start(
+        "--port",
+        FORK_PORT,
+        "--fork-network",
+        ORIGIN_URL,
+        "--fork-block-hash",
+        origin_block["block_hash"],
+        "--accounts",
+        "0",
+    )
+    resp = requests.get(f"{FORK_URL}/fork_status")
+    assert resp.json().get("block") == 1
+
+    resp = requests.post(f"{FORK_URL}/create_block")
+    assert resp.status_code == 200
+    fork_block = get_block(block_number="latest", feeder_gateway_url=FORK_URL)
+    assert fork_block["block_number"] == 2
+    assert fork_block["parent_block_hash"] == origin_block["block_hash"]
+
+
+@devnet_in_background("--port", ORIGIN_PORT, "--accounts", "0")
+def test_fork_block_hash_not_found():
+    """Test forking a block hash which the origin doesn't have"""
+    proc = FORKING_DEVNET.start(
+        "--port",
+        FORK_PORT,
+        "--fork-network",
+        ORIGIN_URL,
+        "--fork-block-hash",
+        "0x123456",
+        "--accounts",
+        "0",
+        stderr=subprocess.PIPE,
+        stdout=subprocess.PIPE,
+    )
+    proc.wait()
+    assert (
+        "Error: Block with the --fork-block-hash 0x123456 not found on the origin"
+        in read_stream(proc.stderr)
+    )
+    assert proc.returncode == 1
+
+
+def test_forwarded_queries():
+    """Test that queries forwarded to origin match those of the origin interface"""
+    forwarded_queries = get_forwarded_queries()
+    for query in [
+        "get_block_by_hash",
+        "get_block_by_number",
+        "get_class_by_hash",
+        "get_state_update",
+        "get_transaction",
+        "get_transaction_receipt",
+        "get_transaction_status",
+        "get_transaction_trace",
+    ]:
+        assert query in forwarded_queries
+
+    # not a query, so not forwarded
+    assert "get_number_of_blocks" not in forwarded_queries
+
+
+@devnet_in_background("--port", ORIGIN_PORT, "--accounts", "0")
+def test_fork_status_redacts_credentials():
+    """Test that credentials of the origin url are not exposed"""
+    credentialed_url = ORIGIN_URL.replace("://", "://user:secret@")
+
+    FORKING_DEVNET.start(
+        "--port",
+        FORK_PORT,
+        "--fork-network",
+        credentialed_url,
+        "--accounts",
+        "0",
+    )
+    resp = requests.get(f"{FORK_URL}/fork_status")
+    url = resp.json().get("url")
+    assert "secret" not in url
+    assert url == ORIGIN_URL.replace("://", "://***@")
+
+
+@devnet_in_background("--port", ORIGIN_PORT, *PREDEPLOY_ACCOUNT_CLI_ARGS)
+def test_forking_with_different_chain_id():
+    """Test that the fork uses its own chain id, regardless of the origin"""
+    deploy_info = declare_and_deploy_with_chargeable(
+        contract=CONTRACT_PATH, inputs=["10"], gateway_url=ORIGIN_URL
+    )
+
+    FORKING_DEVNET.start(
+        "--port",
+        FORK_PORT,
+        "--fork-network",
+        ORIGIN_URL,
+        "--accounts",
+        "0",
+        "--chain-id",
+        StarknetChainId.MAINNET.name,
+    )
+
+    invoke_tx_hash = invoke(
+        calls=[(deploy_info["address"], "increase_balance", [1, 2])],
+        account_address=PREDEPLOYED_ACCOUNT_ADDRESS,
+        private_key=PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+        gateway_url=FORK_URL,
+        chain_id=StarknetChainId.MAINNET,
+    )
+    assert_tx_status(invoke_tx_hash, "ACCEPTED_ON_L2", feeder_gateway_url=FORK_URL)
@@ -83,7 +83,58 @@ def test_block_provided_without_network():
     assert proc.returncode == 1
 
 
-@pytest.mark.parametrize("fork_block", ["-1", "piece of invalid text"])
+def test_block_hash_provided_without_network():
+    """Should fail if block hash provided and network not"""
+    proc = ACTIVE_DEVNET.start(
+        "--fork-block-hash", "0x123", stderr=subprocess.PIPE, stdout=subprocess.PIPE
+    )
+    assert (
+        "Error: --fork-network required if --fork-block-hash present\n"
+        in read_stream(proc.stderr)
+    )
+    assert proc.returncode == 1
+
+
+def test_block_and_block_hash_provided():
+    """Should fail if both block number and block hash are provided"""
+    proc = ACTIVE_DEVNET.start(
+        "--fork-network",
+        "alpha-goerli2",
+        "--fork-block",
+        "1",
+        "--fork-block-hash",
+        "0x123",
+        stderr=subprocess.PIPE,
+        stdout=subprocess.PIPE,
+    )
+    assert (
+        "Error: Only one of {--fork-block,--fork-block-hash} can be provided\n"
+        in read_stream(proc.stderr)
+    )
+    assert proc.returncode == 1
+
+
+@pytest.mark.parametrize("fork_block_hash", ["123", "0xinvalid", hex(2**252)])
+def test_malformed_block_hash(fork_block_hash: str):
+    """Should fail if block hash is not a valid felt"""
+    proc = ACTIVE_DEVNET.start(
+        "--fork-network",
+        "alpha-goerli2",
+        "--fork-block-hash",
+        fork_block_hash,
+        stderr=subprocess.PIPE,
+        stdout=subprocess.PIPE,
+    )
+    assert (
+        "Error: The value of --fork-block-hash must be a 0x-prefixed hex string"
+        in read_stream(proc.stderr)
+    )
+    assert proc.returncode == 1
+
+
+@pytest.mark.parametrize(
+    "fork_block", ["-1", "piece of invalid text", "latest-", "latest--1"]
+)
 def test_malformed_block_id(fork_block: str):
     """Should exit if provided with a negative block number"""
     proc = ACTIVE_DEVNET.start(
@@ -96,7 +147,8 @@ def test_malformed_block_id(fork_block: str):
     )
     assert read_stream(proc.stdout) == ""
     assert (
-        f"The value of --fork-block must be a non-negative integer or 'latest', got: {fork_block}\n"
+        "The value of --fork-block must be a non-negative integer, 'latest' or 'latest-N', "
+        f"got: {fork_block}\n"
         in read_stream(proc.stderr)
     )
 
@@ -115,7 +167,10 @@ def test_too_big_block_id():
         stdout=subprocess.PIPE,
     )
     assert read_stream(proc.stdout) == ""
-    assert f"Block number {too_big_block_id} was not found." in read_stream(proc.stderr)
+    assert (
+        f"Error: The value of --fork-block ({too_big_block_id}) is greater than the latest block"
+        in read_stream(proc.stderr)
+    )
     assert proc.returncode != 0
 
 
@@ -0,0 +1,39 @@
+"""
+Test the state reader used when forking.
+"""
+
+import asyncio
+import json
+
+from starknet_devnet.forked_state import ForkedStateReader
+
+from .shared import CONTRACT_PATH
+
+
+class CountingFeederGatewayClient:
+    """Feeder gateway client stub counting the fetched classes"""
+
+    def __init__(self, class_dict: dict):
+        self.class_dict = class_dict
+        self.class_requests = 0
+
+    async def get_class_by_hash(self, class_hash: str, block_number: int):
+        """Return the same class for every hash"""
+        self.class_requests += 1
+        return self.class_dict
+
+
+def test_fetching_same_class_twice_is_served_from_cache():
+    """The origin should only be asked once for a class"""
+    with open(CONTRACT_PATH, encoding="utf-8") as contract_file:
+        class_dict = json.load(contract_file)
+
+    client = CountingFeederGatewayClient(class_dict)
+    state_reader = ForkedStateReader(feeder_gateway_client=client, block_number=1)
+
+    # pylint: disable=protected-access
+    first = asyncio.run(state_reader._get_class_by_hash(0x123))
+    second = asyncio.run(state_reader._get_class_by_hash(0x123))
+
+    assert first == second
+    assert client.class_requests == 1
@@ -0,0 +1,43 @@
+"""Test configuring the parent hash of the genesis block"""
+
+import subprocess
+
+import pytest
+
+from .util import DevnetBackgroundProc, devnet_in_background, get_block, read_stream
+
+ACTIVE_DEVNET = DevnetBackgroundProc()
+GENESIS_PARENT_HASH = "0x1234abcd"
+
+
+@devnet_in_background("--genesis-parent-hash", GENESIS_PARENT_HASH)
+def test_genesis_block_has_configured_parent_hash():
+    """The genesis block should report the configured parent hash"""
+    genesis_block = get_block(block_number=0)
+    assert int(genesis_block["parent_block_hash"], 16) == int(GENESIS_PARENT_HASH, 16)
+
+
+@devnet_in_background()
+def test_genesis_parent_hash_defaults_to_zero():
+    """Without the option, the parent of the genesis block should be 0x0"""
+    genesis_block = get_block(block_number=0)
+    assert int(genesis_block["parent_block_hash"], 16) == 0
+
+
+@pytest.mark.parametrize(
+    "genesis_parent_hash",
+    ["1234", "0xinvalid", hex(2**251 + 17 * 2**192 + 1)],
+)
+def test_invalid_genesis_parent_hash(genesis_parent_hash):
+    """Malformed values and values outside of the field should be rejected"""
+    proc = ACTIVE_DEVNET.start(
+        "--genesis-parent-hash",
+        genesis_parent_hash,
+        stderr=subprocess.PIPE,
+        stdout=subprocess.PIPE,
+    )
+    assert (
+        "Error: The value of --genesis-parent-hash must be a 0x-prefixed hex string"
+        in read_stream(proc.stderr)
+    )
+    assert proc.returncode == 1
@@ -0,0 +1,55 @@
+"""
+Test instant acceptance of blocks on L1.
+"""
+
+from starkware.starknet.services.api.feeder_gateway.response_objects import (
+    BlockStatus,
+    FinalityStatus,
+)
+
+from .account import declare_and_deploy_with_chargeable
+from .shared import CONTRACT_PATH
+from .util import (
+    assert_tx_status,
+    demand_block_creation,
+    devnet_in_background,
+    get_block,
+    get_transaction_receipt,
+)
+
+
+def _assert_accepted_on_l1(tx_hash: str):
+    assert_tx_status(tx_hash, "ACCEPTED_ON_L1")
+    receipt = get_transaction_receipt(tx_hash)
+    assert receipt["finality_status"] == FinalityStatus.ACCEPTED_ON_L1.name
+
+
+@devnet_in_background("--instant-l1-acceptance")
+def test_transaction_accepted_on_l1_right_after_submission():
+    """Transactions and their blocks should be accepted on L1 immediately"""
+    deploy_info = declare_and_deploy_with_chargeable(CONTRACT_PATH)
+
+    _assert_accepted_on_l1(deploy_info["tx_hash"])
+    assert get_block(block_number="latest")["status"] == BlockStatus.ACCEPTED_ON_L1.name
+    assert get_block(block_number=0)["status"] == BlockStatus.ACCEPTED_ON_L1.name
+
+
+@devnet_in_background("--instant-l1-acceptance", "--blocks-on-demand")
+def test_transaction_accepted_on_l1_on_block_creation():
+    """With blocks on demand, transactions should be accepted on L1 with their block"""
+    deploy_info = declare_and_deploy_with_chargeable(CONTRACT_PATH)
+    assert_tx_status(deploy_info["tx_hash"], "ACCEPTED_ON_L2")
+
+    demand_block_creation()
+
+    _assert_accepted_on_l1(deploy_info["tx_hash"])
+    assert get_block(block_number="latest")["status"] == BlockStatus.ACCEPTED_ON_L1.name
+
+
+@devnet_in_background()
+def test_transaction_accepted_on_l2_by_default():
+    """Without the flag, transactions should not go beyond L2 acceptance"""
+    deploy_info = declare_and_deploy_with_chargeable(CONTRACT_PATH)
+
+    assert_tx_status(deploy_info["tx_hash"], "ACCEPTED_ON_L2")
+    assert get_block(block_number="latest")["status"] == BlockStatus.ACCEPTED_ON_L2.name
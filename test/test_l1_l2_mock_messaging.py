@@ -22,7 +22,13 @@ from .shared import (
     PREDEPLOYED_ACCOUNT_ADDRESS,
     PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
 )
-from .util import assert_tx_status, call, devnet_in_background
+from .util import (
+    assert_hex_equal,
+    assert_tx_status,
+    call,
+    devnet_in_background,
+    get_transaction_receipt,
+)
 
 USER_ID = 1
 L1_CONTRACT_ADDRESS = "0xE7F1725E7734CE288F8367E1BB143E90BB3F0512"
@@ -58,6 +64,14 @@ def consume_message_from_l2(req_dict: dict):
     )
 
 
+def send_message_to_l1(req_dict: dict):
+    """Sends the dict in a POST request and returns the response data."""
+    return requests.post(
+        f"{APP_URL}/postman/send_message_to_l1",
+        json=req_dict,
+    )
+
+
 def _post_through_test_client(url: str, data: dict) -> TestResponse:
     return app.test_client().post(
         url,
@@ -168,7 +182,7 @@ def test_consume_message_from_l2_deploy_execute():
         account_address=PREDEPLOYED_ACCOUNT_ADDRESS,
         private_key=PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
     )
-    invoke(
+    withdraw_tx_hash = invoke(
         calls=[
             (
                 deploy_info["address"],
@@ -180,6 +194,14 @@ def test_consume_message_from_l2_deploy_execute():
         private_key=PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
     )
 
+    # the message is sent by the contract, not by the invoking account
+    receipt = get_transaction_receipt(withdraw_tx_hash)
+    assert len(receipt["l2_to_l1_messages"]) == 1
+    message = receipt["l2_to_l1_messages"][0]
+    assert_hex_equal(message["from_address"], deploy_info["address"])
+    assert message["to_address"].lower() == L1_CONTRACT_ADDRESS.lower()
+    assert message["payload"] == CONSUME_PAYLOAD
+
     response = consume_message_from_l2(
         {
             "l2_contract_address": deploy_info["address"],
@@ -239,3 +261,43 @@ def test_consume_message_from_l2_execute_without_deploy():
     assert response.json.get("code") == str(
         StarknetErrorCode.L1_TO_L2_MESSAGE_ZEROED_COUNTER
     )
+
+
+@devnet_in_background()
+def test_consume_injected_message_from_l2():
+    """Test consuming a message injected without a contract sending it"""
+    message = {
+        "l2_contract_address": L2_CONTRACT_ADDRESS,
+        "l1_contract_address": L1_CONTRACT_ADDRESS,
+        "payload": CONSUME_PAYLOAD,
+    }
+
+    response = send_message_to_l1(message)
+    assert response.status_code == 200
+    message_hash = response.json()["message_hash"]
+
+    response = consume_message_from_l2(message)
+    assert response.status_code == 200
+    assert response.json()["message_hash"] == message_hash
+
+    # consumed only once
+    response = consume_message_from_l2(message)
+    assert response.status_code == 400
+    assert response.json().get("code") == str(
+        StarknetErrorCode.L1_TO_L2_MESSAGE_ZEROED_COUNTER
+    )
+
+
+def test_send_message_to_l1_without_data():
+    """Test injecting a message to L1 without data"""
+    response = _post_through_test_client(
+        "/postman/send_message_to_l1",
+        {
+            "l2_contract_address": "",
+            "l1_contract_address": "",
+            "payload": "",
+        },
+    )
+
+    assert response.status_code == 400
+    assert response.json.get("code") == str(StarkErrorCode.MALFORMED_REQUEST)
@@ -0,0 +1,41 @@
+"""Test the format of server logs"""
+
+import json
+import subprocess
+
+from .util import DevnetBackgroundProc, read_stream, terminate_and_wait
+
+ACTIVE_DEVNET = DevnetBackgroundProc()
+
+
+def test_json_log_format():
+    """Access logs should be written as JSON lines"""
+    proc = ACTIVE_DEVNET.start("--log-format", "json", stderr=subprocess.PIPE)
+    terminate_and_wait(proc)
+
+    # the health check performed on startup is logged
+    access_log_lines = [
+        line for line in read_stream(proc.stderr).splitlines() if "/is_alive" in line
+    ]
+    assert access_log_lines
+
+    for line in access_log_lines:
+        log_record = json.loads(line)
+        assert log_record["logger"] == "gunicorn.access"
+        assert log_record["level"] == "INFO"
+        assert "GET /is_alive" in log_record["message"]
+
+
+def test_invalid_log_format():
+    """Devnet should not start with an unknown log format"""
+    proc = ACTIVE_DEVNET.start(
+        "--log-format",
+        "xml",
+        stderr=subprocess.PIPE,
+        stdout=subprocess.PIPE,
+    )
+    assert (
+        "Error: Invalid --log-format option: xml. Valid options: plain, json"
+        in read_stream(proc.stderr)
+    )
+    assert proc.returncode == 1
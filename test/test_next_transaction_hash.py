@@ -0,0 +1,88 @@
+"""Test overriding the hash of the next transaction"""
+
+import requests
+from starkware.starknet.definitions.error_codes import StarknetErrorCode
+
+from .account import declare, declare_and_deploy_with_chargeable, invoke
+from .settings import APP_URL
+from .shared import (
+    CONTRACT_PATH,
+    PREDEPLOY_ACCOUNT_CLI_ARGS,
+    PREDEPLOYED_ACCOUNT_ADDRESS,
+    PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+)
+from .util import assert_tx_status, devnet_in_background, get_transaction_receipt, mint
+
+CHOSEN_TX_HASH = "0x123abc"
+
+
+def set_next_transaction_hash(tx_hash: str):
+    """Send the request for overriding the next transaction hash"""
+    return requests.post(
+        f"{APP_URL}/set_next_transaction_hash", json={"transactionHash": tx_hash}
+    )
+
+
+@devnet_in_background(*PREDEPLOY_ACCOUNT_CLI_ARGS)
+def test_next_transaction_has_chosen_hash():
+    """Submit a tx after setting the hash and expect it to be retrievable by it"""
+    resp = set_next_transaction_hash(CHOSEN_TX_HASH)
+    assert resp.status_code == 200
+    assert resp.json() == {"next_transaction_hash": CHOSEN_TX_HASH}
+
+    # transactions sent by Devnet itself don't consume the override
+    mint_resp = mint(PREDEPLOYED_ACCOUNT_ADDRESS, amount=10)
+    assert mint_resp["tx_hash"] != CHOSEN_TX_HASH
+
+    declare_info = declare(
+        contract_path=CONTRACT_PATH,
+        account_address=PREDEPLOYED_ACCOUNT_ADDRESS,
+        private_key=PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+        max_fee=int(1e18),
+    )
+    assert declare_info["tx_hash"] == CHOSEN_TX_HASH
+    assert_tx_status(CHOSEN_TX_HASH, "ACCEPTED_ON_L2")
+
+    receipt = get_transaction_receipt(CHOSEN_TX_HASH)
+    assert receipt["transaction_hash"] == CHOSEN_TX_HASH
+
+    # the override is cleared after use
+    next_mint_resp = mint(PREDEPLOYED_ACCOUNT_ADDRESS, amount=10)
+    assert next_mint_resp["tx_hash"] != CHOSEN_TX_HASH
+
+
+@devnet_in_background(*PREDEPLOY_ACCOUNT_CLI_ARGS)
+def test_reverted_transaction_keeps_chosen_hash():
+    """A reverted tx should not consume the override; the next successful one should"""
+    contract_address = declare_and_deploy_with_chargeable(
+        contract=CONTRACT_PATH, inputs=[0]
+    )["address"]
+    assert set_next_transaction_hash(CHOSEN_TX_HASH).status_code == 200
+
+    reverted_tx_hash = invoke(
+        calls=[(contract_address, "increase_balance", [10])],  # one param missing
+        account_address=PREDEPLOYED_ACCOUNT_ADDRESS,
+        private_key=PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+        # specify max_fee to prevent estimateFee, which fails due to invalid args
+        max_fee=int(1e15),
+    )
+    assert_tx_status(reverted_tx_hash, "REVERTED")
+    assert reverted_tx_hash != CHOSEN_TX_HASH
+
+    tx_hash = invoke(
+        calls=[(contract_address, "increase_balance", [10, 20])],
+        account_address=PREDEPLOYED_ACCOUNT_ADDRESS,
+        private_key=PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+    )
+    assert tx_hash == CHOSEN_TX_HASH
+    assert_tx_status(CHOSEN_TX_HASH, "ACCEPTED_ON_L2")
+
+
+@devnet_in_background(*PREDEPLOY_ACCOUNT_CLI_ARGS)
+def test_colliding_hash_rejected():
+    """Setting the hash of an existing transaction should fail"""
+    tx_hash = mint(PREDEPLOYED_ACCOUNT_ADDRESS, amount=10)["tx_hash"]
+
+    resp = set_next_transaction_hash(tx_hash)
+    assert resp.status_code == 400
+    assert resp.json()["code"] == str(StarknetErrorCode.DUPLICATED_TRANSACTION)
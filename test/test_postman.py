@@ -383,6 +383,53 @@ def test_postman():
     _l1_l2_message_exchange(web3, l1l2_example_contract, l2_contract_address)
 
 
+@pytest.mark.web3_messaging
+@devnet_in_background()
+def test_postman_flush_injected_message():
+    """Test flushing a message to L1 injected without a contract sending it"""
+    init_messaging_contract()
+    web3 = Web3(Web3.HTTPProvider(L1_URL))
+    web3.eth.default_account = web3.eth.accounts[0]
+    starknet_messaging_contract, l1l2_example_contract = deploy_l1_contracts(web3)
+    load_messaging_contract(starknet_messaging_contract.address)
+
+    from_address = "0x123"
+    payload = [0, USER_ID, 1000]
+    response = requests.post(
+        f"{APP_URL}/postman/send_message_to_l1",
+        json={
+            "l2_contract_address": from_address,
+            "l1_contract_address": l1l2_example_contract.address,
+            "payload": [hex(value) for value in payload],
+        },
+    )
+    assert response.status_code == 200
+
+    assert_flush_response(
+        response=flush(),
+        expected_from_l1=[],
+        expected_from_l2=[
+            {
+                "from_address": from_address,
+                "to_address": l1l2_example_contract.address,
+                "payload": payload,
+            }
+        ],
+        expected_l1_provider=L1_URL,
+        expected_generated_l2_transactions=0,
+    )
+
+    event_filter = starknet_messaging_contract.events.LogMessageToL1.create_filter(
+        fromBlock=0, toBlock="latest"
+    )
+    new_event_entries = event_filter.get_new_entries()
+    assert len(new_event_entries) == 1, f"Wrong entries: {new_event_entries}"
+    event = new_event_entries[0].args
+    assert event.fromAddress == int(from_address, 16)
+    assert event.toAddress == l1l2_example_contract.address
+    assert event.payload == payload
+
+
 def _load_l1_messaging_contract(req_dict: dict):
     """Load L1 messaging contract"""
     return requests.post(
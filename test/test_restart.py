@@ -5,7 +5,7 @@ Test restart endpoint
 import pytest
 import requests
 
-from .account import declare_and_deploy_with_chargeable, invoke
+from .account import declare_and_deploy_with_chargeable, get_nonce, invoke
 from .settings import APP_URL
 from .shared import (
     ABI_PATH,
@@ -15,6 +15,7 @@ from .shared import (
     PREDEPLOYED_ACCOUNT_ADDRESS,
     PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
 )
+from .test_account import get_account_balance
 from .util import (
     assert_transaction_not_received,
     assert_tx_status,
@@ -130,3 +131,50 @@ def test_gas_price_unaffected_by_restart():
     assert block_after["block_hash"] != block_before["block_hash"]
     gas_price_after = str(int(block_after["gas_price"], 16))
     assert gas_price_after == GAS_PRICE
+
+
+def get_predeployed_accounts():
+    """Get predeployed accounts"""
+    return requests.get(f"{APP_URL}/predeployed_accounts").json()
+
+
+@pytest.mark.restart
+@devnet_in_background("--accounts", "2")
+def test_predeployed_accounts_reset():
+    """Checks that accounts keep their addresses, but have nonces and balances reset"""
+    accounts_before = get_predeployed_accounts()
+    account = accounts_before[0]
+
+    deploy_info = deploy_contract()
+    invoke(
+        calls=[(deploy_info["address"], "increase_balance", [10, 20])],
+        account_address=account["address"],
+        private_key=int(account["private_key"], 16),
+    )
+    assert get_nonce(account["address"]) == 1
+    assert get_account_balance(account["address"]) < account["initial_balance"]
+
+    restart()
+
+    # same accounts even though no seed was provided
+    assert get_predeployed_accounts() == accounts_before
+    for account in accounts_before:
+        assert get_nonce(account["address"]) == 0
+        assert get_account_balance(account["address"]) == account["initial_balance"]
+
+
+@pytest.mark.restart
+@devnet_in_background()
+def test_next_transaction_hash_cleared():
+    """Checks that the next transaction hash set before restart is not used after it"""
+    next_tx_hash = "0x123abc"
+    res = requests.post(
+        f"{APP_URL}/set_next_transaction_hash", json={"transactionHash": next_tx_hash}
+    )
+    assert res.status_code == 200
+
+    restart()
+
+    # neither the declaration nor the deployment gets the hash
+    deploy_contract()
+    assert_transaction_not_received(tx_hash=next_tx_hash)
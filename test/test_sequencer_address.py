@@ -0,0 +1,71 @@
+"""Test configuring the address of the sequencer"""
+
+import subprocess
+
+import pytest
+import requests
+
+from .account import invoke
+from .settings import APP_URL
+from .shared import (
+    PREDEPLOY_ACCOUNT_CLI_ARGS,
+    PREDEPLOYED_ACCOUNT_ADDRESS,
+    PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+)
+from .test_account import deploy_empty_contract
+from .util import (
+    DevnetBackgroundProc,
+    assert_hex_equal,
+    devnet_in_background,
+    get_block,
+    read_stream,
+)
+
+ACTIVE_DEVNET = DevnetBackgroundProc()
+SEQUENCER_ADDRESS = "0x1234abcd"
+
+
+def _assert_sequencer_address_used():
+    contract_address = deploy_empty_contract()["address"]
+    invoke(
+        calls=[(contract_address, "increase_balance", [1, 2])],
+        account_address=PREDEPLOYED_ACCOUNT_ADDRESS,
+        private_key=PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+    )
+    latest_block = get_block(block_number="latest")
+    assert_hex_equal(latest_block["sequencer_address"], SEQUENCER_ADDRESS)
+
+    sequencer_balance = requests.get(f"{APP_URL}/sequencer_balance").json()
+    assert_hex_equal(sequencer_balance["address"], SEQUENCER_ADDRESS)
+    assert sequencer_balance["amount"] > 0
+
+    config = requests.get(f"{APP_URL}/config").json()
+    assert_hex_equal(config["sequencer_address"], SEQUENCER_ADDRESS)
+
+
+@devnet_in_background(
+    *PREDEPLOY_ACCOUNT_CLI_ARGS, "--sequencer-address", SEQUENCER_ADDRESS
+)
+def test_sequencer_address():
+    """The configured sequencer should collect fees, also after restart"""
+    _assert_sequencer_address_used()
+
+    resp = requests.post(f"{APP_URL}/restart")
+    assert resp.status_code == 200
+    _assert_sequencer_address_used()
+
+
+@pytest.mark.parametrize("sequencer_address", ["1234", "0xinvalid", "0x0"])
+def test_invalid_sequencer_address(sequencer_address):
+    """Malformed values and invalid addresses should be rejected"""
+    proc = ACTIVE_DEVNET.start(
+        "--sequencer-address",
+        sequencer_address,
+        stderr=subprocess.PIPE,
+        stdout=subprocess.PIPE,
+    )
+    assert (
+        "Error: The value of --sequencer-address must be a 0x-prefixed hex string"
+        in read_stream(proc.stderr)
+    )
+    assert proc.returncode == 1
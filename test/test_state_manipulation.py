@@ -0,0 +1,266 @@
+"""Test writing directly to the state of Devnet"""
+
+import requests
+from starkware.starknet.definitions.error_codes import StarknetErrorCode
+from starkware.starknet.public.abi import get_selector_from_name
+from starkware.starkware_utils.error_handling import StarkErrorCode
+
+from starknet_devnet.chargeable_account import ChargeableAccount
+
+from .account import declare, declare_and_deploy_with_chargeable, get_nonce, invoke
+from .settings import APP_URL
+from .shared import (
+    ABI_PATH,
+    CONTRACT_PATH,
+    PREDEPLOY_ACCOUNT_CLI_ARGS,
+    PREDEPLOYED_ACCOUNT_ADDRESS,
+    PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+    REPLACEABLE_CONTRACT_PATH,
+    REPLACING_ABI_PATH,
+    REPLACING_CONTRACT_PATH,
+)
+from .test_state_update import get_state_update
+from .testnet_deployment import TESTNET_CONTRACT_ADDRESS, TESTNET_FORK_PARAMS
+from .util import (
+    assert_class_hash_at_address,
+    assert_hex_equal,
+    assert_tx_status,
+    call,
+    demand_block_creation,
+    devnet_in_background,
+    get_block,
+)
+
+BALANCE_KEY = hex(get_selector_from_name("balance"))
+
+
+def set_storage_at(contract_address: str, key: str, value: str):
+    """Send a request for writing `value` to storage"""
+    return requests.post(
+        f"{APP_URL}/set_storage_at",
+        json={"contractAddress": contract_address, "key": key, "value": value},
+    )
+
+
+def replace_class(contract_address: str, class_hash: str):
+    """Send a request for replacing the class of the contract at `contract_address`"""
+    return requests.post(
+        f"{APP_URL}/replace_class",
+        json={"contractAddress": contract_address, "classHash": class_hash},
+    )
+
+
+def set_nonce(contract_address: str, nonce: str):
+    """Send a request for setting the nonce of the contract at `contract_address`"""
+    return requests.post(
+        f"{APP_URL}/set_nonce",
+        json={"contractAddress": contract_address, "nonce": nonce},
+    )
+
+
+def get_contract_storage(contract_address: str, **block_id):
+    """Get the non-zero storage of the contract at `contract_address`"""
+    return requests.get(
+        f"{APP_URL}/contract_storage",
+        params={"contractAddress": contract_address, **block_id},
+    )
+
+
+def _deploy_contract() -> str:
+    return declare_and_deploy_with_chargeable(CONTRACT_PATH, inputs=["0"])["address"]
+
+
+def _get_balance(contract_address: str, block_number="latest") -> int:
+    return int(
+        call(
+            function="get_balance",
+            address=contract_address,
+            abi_path=ABI_PATH,
+            block_number=block_number,
+        )
+    )
+
+
+@devnet_in_background()
+def test_set_storage_at():
+    """Assert the written value is readable and included in a new block"""
+    contract_address = _deploy_contract()
+
+    resp = set_storage_at(contract_address, BALANCE_KEY, "0x42")
+    assert resp.status_code == 200, resp.json()
+    assert _get_balance(contract_address) == 0x42
+
+    latest_block = get_block(block_number="latest")
+    assert resp.json()["block_hash"] == latest_block["block_hash"]
+    assert latest_block["transactions"] == []
+
+    storage_diffs = get_state_update()["state_diff"]["storage_diffs"]
+    assert {int(address, 16): diff for address, diff in storage_diffs.items()} == {
+        int(contract_address, 16): [{"key": BALANCE_KEY, "value": "0x42"}]
+    }
+
+
+@devnet_in_background("--blocks-on-demand")
+def test_set_storage_at_on_demand():
+    """Assert the written value becomes part of the pending block"""
+    contract_address = _deploy_contract()
+    demand_block_creation()
+
+    resp = set_storage_at(contract_address, BALANCE_KEY, "0x42")
+    assert resp.status_code == 200, resp.json()
+    assert resp.json()["block_hash"] is None
+
+    assert _get_balance(contract_address, block_number="pending") == 0x42
+    assert _get_balance(contract_address, block_number="latest") == 0
+
+    demand_block_creation()
+    assert _get_balance(contract_address, block_number="latest") == 0x42
+
+
+@devnet_in_background()
+def test_set_storage_at_undeployed():
+    """Assert writing to storage of an undeployed contract fails"""
+    resp = set_storage_at("0x123", BALANCE_KEY, "0x42")
+    assert resp.status_code == 400
+    assert resp.json()["code"] == str(StarknetErrorCode.UNINITIALIZED_CONTRACT)
+
+
+@devnet_in_background()
+def test_set_storage_at_invalid_value():
+    """Assert values outside of the field are rejected"""
+    contract_address = _deploy_contract()
+
+    resp = set_storage_at(contract_address, BALANCE_KEY, hex(2**256))
+    assert resp.status_code == 400
+    assert resp.json()["code"] == str(StarkErrorCode.MALFORMED_REQUEST)
+
+
+def _declare_replacing_class() -> str:
+    return declare(
+        REPLACING_CONTRACT_PATH,
+        account_address=hex(ChargeableAccount.ADDRESS),
+        private_key=ChargeableAccount.PRIVATE_KEY,
+        max_fee=int(1e18),
+    )["class_hash"]
+
+
+@devnet_in_background()
+def test_replace_class():
+    """Assert the contract uses the new class and the replacement is in a new block"""
+    deploy_info = declare_and_deploy_with_chargeable(REPLACEABLE_CONTRACT_PATH)
+    contract_address = deploy_info["address"]
+    new_class_hash = _declare_replacing_class()
+
+    resp = replace_class(contract_address, new_class_hash)
+    assert resp.status_code == 200, resp.json()
+
+    latest_block = get_block(block_number="latest")
+    assert resp.json()["block_hash"] == latest_block["block_hash"]
+    assert latest_block["transactions"] == []
+
+    assert_class_hash_at_address(contract_address, new_class_hash)
+    foo_after_replacement = call(
+        function="foo", address=contract_address, abi_path=REPLACING_ABI_PATH
+    )
+    assert int(foo_after_replacement) == 43
+
+    replaced_classes = get_state_update()["state_diff"]["replaced_classes"]
+    assert len(replaced_classes) == 1
+    assert_hex_equal(replaced_classes[0]["address"], contract_address)
+    assert_hex_equal(replaced_classes[0]["class_hash"], new_class_hash)
+
+
+@devnet_in_background()
+def test_replace_class_undeployed():
+    """Assert replacing the class of an undeployed contract fails"""
+    new_class_hash = _declare_replacing_class()
+
+    resp = replace_class("0x123", new_class_hash)
+    assert resp.status_code == 400
+    assert resp.json()["code"] == str(StarknetErrorCode.UNINITIALIZED_CONTRACT)
+
+
+@devnet_in_background()
+def test_replace_class_undeclared():
+    """Assert replacing with an undeclared class fails"""
+    contract_address = _deploy_contract()
+
+    resp = replace_class(contract_address, "0x123")
+    assert resp.status_code == 400
+    assert resp.json()["code"] == str(StarknetErrorCode.UNDECLARED_CLASS)
+
+
+@devnet_in_background()
+def test_get_contract_storage():
+    """Assert only non-zero storage is returned, also for past blocks"""
+    contract_address = _deploy_contract()
+    deployment_block = get_block(block_number="latest")
+
+    resp = get_contract_storage(contract_address)
+    assert resp.status_code == 200, resp.json()
+    assert resp.json() == {"storage": {}, "partial": False}
+
+    set_storage_at(contract_address, BALANCE_KEY, "0x42")
+    resp = get_contract_storage(contract_address)
+    assert resp.status_code == 200, resp.json()
+    assert resp.json() == {"storage": {BALANCE_KEY: "0x42"}, "partial": False}
+
+    resp = get_contract_storage(
+        contract_address, blockNumber=deployment_block["block_number"]
+    )
+    assert resp.status_code == 200, resp.json()
+    assert resp.json() == {"storage": {}, "partial": False}
+
+
+@devnet_in_background(*TESTNET_FORK_PARAMS)
+def test_get_contract_storage_when_forking():
+    """Assert the storage is reported as partial when forking"""
+    resp = get_contract_storage(TESTNET_CONTRACT_ADDRESS)
+    assert resp.status_code == 200, resp.json()
+    assert resp.json()["partial"] is True
+
+
+@devnet_in_background()
+def test_get_contract_storage_undeployed():
+    """Assert getting the storage of an undeployed contract fails"""
+    resp = get_contract_storage("0x123")
+    assert resp.status_code == 400
+    assert resp.json()["code"] == str(StarknetErrorCode.UNINITIALIZED_CONTRACT)
+
+
+@devnet_in_background(*PREDEPLOY_ACCOUNT_CLI_ARGS)
+def test_set_nonce():
+    """Assert the nonce can be increased and decreased and is used by transactions"""
+    contract_address = _deploy_contract()
+
+    for nonce in [5, 2]:
+        resp = set_nonce(PREDEPLOYED_ACCOUNT_ADDRESS, hex(nonce))
+        assert resp.status_code == 200, resp.json()
+        assert get_nonce(PREDEPLOYED_ACCOUNT_ADDRESS) == nonce
+
+        latest_block = get_block(block_number="latest")
+        assert resp.json()["block_hash"] == latest_block["block_hash"]
+
+        tx_hash = invoke(
+            calls=[(contract_address, "increase_balance", [10, 20])],
+            account_address=PREDEPLOYED_ACCOUNT_ADDRESS,
+            private_key=PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+            nonce=nonce,
+        )
+        assert_tx_status(tx_hash, "ACCEPTED_ON_L2")
+        assert get_nonce(PREDEPLOYED_ACCOUNT_ADDRESS) == nonce + 1
+
+    # the block created by decreasing the nonce contains the new nonce
+    state_diff = get_state_update(block_hash=latest_block["block_hash"])["state_diff"]
+    assert {
+        int(address, 16): int(nonce, 16)
+        for address, nonce in state_diff["nonces"].items()
+    } == {int(PREDEPLOYED_ACCOUNT_ADDRESS, 16): 2}
+
+
+@devnet_in_background()
+def test_set_nonce_undeployed():
+    """Assert setting the nonce of an undeployed contract fails"""
+    resp = set_nonce("0x123", "0x5")
+    assert resp.status_code == 400
+    assert resp.json()["code"] == str(StarknetErrorCode.UNINITIALIZED_CONTRACT)
@@ -5,11 +5,14 @@ Test block timestamps
 import time
 
 import pytest
+import requests
+from starkware.starknet.definitions.error_codes import StarknetErrorCode
 
 from starknet_devnet.block_info_generator import BlockInfo, BlockInfoGenerator
 from starknet_devnet.general_config import DEFAULT_GENERAL_CONFIG
 
 from .account import declare_and_deploy_with_chargeable
+from .settings import APP_URL
 from .shared import ARTIFACTS_PATH
 from .util import call, devnet_in_background, get_block, increase_time, set_time
 
@@ -69,9 +72,10 @@ def test_increase_time():
     assert ts_after_deploy == first_block_ts
 
     # increase time by 1 day
-    increase_time(86400)
+    response = increase_time(86400)
     ts_after_increase_time = get_ts_from_last_block()
     assert ts_after_increase_time >= ts_after_deploy + 86400
+    assert response.json()["block_timestamp"] == ts_after_increase_time
 
 
 @pytest.mark.timestamps
@@ -198,3 +202,111 @@ def test_lite_mode_compatibility():
 
     time_from_contract = get_ts_from_contract(address=deploy_info["address"])
     assert time_from_contract == 100
+
+
+@pytest.mark.timestamps
+def test_block_info_generator_with_block_time_increment():
+    """Test block info generator ignoring wall clock"""
+    block_info = BlockInfo.create_for_testing(block_number=0, block_timestamp=0)
+    generator = BlockInfoGenerator(start_time=100, block_time_increment=5)
+
+    timestamps = [
+        generator.next_block(
+            block_info=block_info, general_config=DEFAULT_GENERAL_CONFIG
+        ).block_timestamp
+        for _ in range(3)
+    ]
+    assert timestamps == [100, 105, 110]
+
+    # increase is applied only once
+    generator.increase_time(1000)
+    timestamps = [
+        generator.next_block(
+            block_info=block_info, general_config=DEFAULT_GENERAL_CONFIG
+        ).block_timestamp
+        for _ in range(2)
+    ]
+    assert timestamps == [1115, 1120]
+
+    # set time becomes the new base
+    generator.set_next_block_time(10)
+    timestamps = [
+        generator.next_block(
+            block_info=block_info, general_config=DEFAULT_GENERAL_CONFIG
+        ).block_timestamp
+        for _ in range(2)
+    ]
+    assert timestamps == [10, 15]
+
+
+@pytest.mark.timestamps
+@devnet_in_background(
+    "--start-time", str(SET_TIME_ARGUMENT), "--block-time-increment", "1"
+)
+def test_block_time_increment():
+    """Test that rapidly created blocks have strictly increasing timestamps"""
+    deploy_info = deploy_ts_contract()
+    deploy_ts_contract()
+
+    latest_block = get_block()
+    timestamps = [
+        get_block(block_number=block_number)["timestamp"]
+        for block_number in range(latest_block["block_number"] + 1)
+    ]
+    assert timestamps == [
+        SET_TIME_ARGUMENT + block_number for block_number in range(len(timestamps))
+    ]
+
+    ts_from_contract = get_ts_from_contract(deploy_info["address"])
+    assert ts_from_contract == latest_block["timestamp"]
+
+
+def get_block_by_timestamp(timestamp):
+    """Get the block with the greatest timestamp not exceeding `timestamp`"""
+    return requests.get(
+        f"{APP_URL}/block_by_timestamp", params={"timestamp": timestamp}
+    )
+
+
+@pytest.mark.timestamps
+@devnet_in_background(
+    "--start-time", str(SET_TIME_ARGUMENT), "--block-time-increment", "1"
+)
+def test_get_block_by_timestamp():
+    """Test finding the block in which a timestamp landed"""
+    deploy_ts_contract()  # declaration in block 1, deployment in block 2
+    latest_block = get_block()
+    assert latest_block["block_number"] == 2
+    assert latest_block["timestamp"] == SET_TIME_ARGUMENT + 2
+
+    resp = get_block_by_timestamp(SET_TIME_ARGUMENT + 1)
+    assert resp.status_code == 200, resp.json()
+    assert resp.json()["block_number"] == 1
+    assert resp.json() == get_block(block_number=1)
+
+    resp = get_block_by_timestamp(latest_block["timestamp"] + 1000)
+    assert resp.status_code == 200, resp.json()
+    assert resp.json() == latest_block
+
+    # of blocks sharing a timestamp, the latest one is returned
+    set_time(SET_TIME_ARGUMENT)
+    block_with_set_time = get_block()
+    assert block_with_set_time["block_number"] == 3
+    assert block_with_set_time["timestamp"] == SET_TIME_ARGUMENT
+    assert get_block(block_number=0)["timestamp"] == SET_TIME_ARGUMENT
+
+    resp = get_block_by_timestamp(SET_TIME_ARGUMENT)
+    assert resp.status_code == 200, resp.json()
+    assert resp.json() == block_with_set_time
+
+
+@pytest.mark.timestamps
+@devnet_in_background("--start-time", str(SET_TIME_ARGUMENT))
+def test_get_block_by_timestamp_before_genesis():
+    """Test that timestamps predating the genesis block are not found"""
+    resp = get_block_by_timestamp(SET_TIME_ARGUMENT - 1)
+    assert resp.status_code == 400
+    assert resp.json()["code"] == str(StarknetErrorCode.BLOCK_NOT_FOUND)
+
+    resp = get_block_by_timestamp("not an int")
+    assert resp.status_code == 400
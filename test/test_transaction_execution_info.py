@@ -0,0 +1,61 @@
+"""Test retrieving the raw execution info of transactions"""
+
+import requests
+from starkware.starknet.definitions.error_codes import StarknetErrorCode
+
+from .account import declare_and_deploy_with_chargeable, invoke
+from .settings import APP_URL
+from .shared import (
+    CONTRACT_PATH,
+    PREDEPLOY_ACCOUNT_CLI_ARGS,
+    PREDEPLOYED_ACCOUNT_ADDRESS,
+    PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+)
+from .util import devnet_in_background, get_transaction_receipt
+
+
+def get_transaction_execution_info(tx_hash: str) -> requests.Response:
+    """Get the execution info of the transaction with `tx_hash`"""
+    return requests.get(
+        f"{APP_URL}/transaction_execution_info", params={"transactionHash": tx_hash}
+    )
+
+
+@devnet_in_background(*PREDEPLOY_ACCOUNT_CLI_ARGS)
+def test_transaction_execution_info():
+    """Invoke a contract and expect its execution info to be available"""
+    deploy_info = declare_and_deploy_with_chargeable(CONTRACT_PATH, inputs=["0"])
+    invoke_tx_hash = invoke(
+        calls=[(deploy_info["address"], "increase_balance", [10, 20])],
+        account_address=PREDEPLOYED_ACCOUNT_ADDRESS,
+        private_key=PREDEPLOYED_ACCOUNT_PRIVATE_KEY,
+    )
+
+    resp = get_transaction_execution_info(invoke_tx_hash)
+    assert resp.status_code == 200, resp.json()
+    execution_info = resp.json()
+
+    for key in [
+        "validate_info",
+        "call_info",
+        "fee_transfer_info",
+        "actual_fee",
+        "actual_resources",
+        "tx_type",
+    ]:
+        assert key in execution_info
+
+    # details abstracted away by the trace
+    assert "n_steps" in execution_info["actual_resources"]
+    assert "execution_resources" in execution_info["call_info"]
+
+    receipt = get_transaction_receipt(invoke_tx_hash)
+    assert int(execution_info["actual_fee"], 16) == int(receipt["actual_fee"], 16)
+
+
+@devnet_in_background()
+def test_transaction_execution_info_of_unknown_transaction():
+    """Expect an error for a transaction not executed by Devnet"""
+    resp = get_transaction_execution_info("0x123")
+    assert resp.status_code == 400
+    assert resp.json()["code"] == str(StarknetErrorCode.TRANSACTION_NOT_FOUND)